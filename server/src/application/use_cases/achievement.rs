@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    repositories::achievement::AchievementRepository,
+    value_objects::badge_model::{AwardedBadgeModel, BadgeModel},
+};
+
+pub struct AchievementUseCase<T>
+where
+    T: AchievementRepository + Send + Sync,
+{
+    achievement_repository: Arc<T>,
+}
+
+impl<T> AchievementUseCase<T>
+where
+    T: AchievementRepository + Send + Sync,
+{
+    pub fn new(achievement_repository: Arc<T>) -> Self {
+        Self {
+            achievement_repository,
+        }
+    }
+
+    pub async fn get_definitions(&self) -> Result<Vec<BadgeModel>> {
+        let entities = self.achievement_repository.get_definitions().await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    pub async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<AwardedBadgeModel>> {
+        let awarded = self.achievement_repository.get_awarded(brawler_id).await?;
+
+        let result = awarded
+            .iter()
+            .map(|(brawler_badge, badge)| brawler_badge.to_model(badge))
+            .collect();
+        Ok(result)
+    }
+}
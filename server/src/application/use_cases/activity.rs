@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    repositories::activity::ActivityRepository,
+    value_objects::activity_model::{ActivityEventModel, ActivityFilter},
+};
+
+const DEFAULT_FEED_LIMIT: i64 = 20;
+const MAX_FEED_LIMIT: i64 = 100;
+
+pub struct ActivityUseCase<T>
+where
+    T: ActivityRepository + Send + Sync,
+{
+    activity_repository: Arc<T>,
+}
+
+impl<T> ActivityUseCase<T>
+where
+    T: ActivityRepository + Send + Sync,
+{
+    pub fn new(activity_repository: Arc<T>) -> Self {
+        Self {
+            activity_repository,
+        }
+    }
+
+    pub async fn get_global(&self, filter: &ActivityFilter) -> Result<Vec<ActivityEventModel>> {
+        self.get_feed(None, filter).await
+    }
+
+    pub async fn get_personal(
+        &self,
+        brawler_id: i32,
+        filter: &ActivityFilter,
+    ) -> Result<Vec<ActivityEventModel>> {
+        self.get_feed(Some(brawler_id), filter).await
+    }
+
+    async fn get_feed(
+        &self,
+        viewer_id: Option<i32>,
+        filter: &ActivityFilter,
+    ) -> Result<Vec<ActivityEventModel>> {
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_FEED_LIMIT)
+            .clamp(1, MAX_FEED_LIMIT);
+
+        let result = self
+            .activity_repository
+            .get_feed(viewer_id, filter.before, limit)
+            .await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use crate::{
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, brawlers::BrawlerRepository,
+            leaderboard::LeaderboardRepository,
+        },
+        value_objects::brawler_model::RegisterBrawlerModel,
+    },
+    infrastructure::argon2::hash,
+};
+
+pub struct AdminUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: LeaderboardRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    achievement_repository: Arc<T2>,
+    leaderboard_repository: Arc<T3>,
+}
+
+impl<T1, T2, T3> AdminUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: LeaderboardRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        achievement_repository: Arc<T2>,
+        leaderboard_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            achievement_repository,
+            leaderboard_repository,
+        }
+    }
+
+    pub async fn create_admin(
+        &self,
+        mut register_brawler_model: RegisterBrawlerModel,
+    ) -> Result<i32> {
+        if register_brawler_model.password.is_empty() {
+            return Err(anyhow::anyhow!("Password must not be empty"));
+        }
+        register_brawler_model.password = hash(register_brawler_model.password)?;
+
+        self.brawler_repository
+            .register_admin(register_brawler_model.to_entity())
+            .await
+    }
+
+    pub async fn reset_password(&self, username: String, password: String) -> Result<i32> {
+        if password.is_empty() {
+            return Err(anyhow::anyhow!("Password must not be empty"));
+        }
+        let brawler_id = self.find_id(username).await?;
+
+        self.brawler_repository
+            .update_password(brawler_id, hash(password)?)
+            .await?;
+        Ok(brawler_id)
+    }
+
+    /// Banned brawlers can no longer log in nor use a token they already hold;
+    /// lifting the ban clears the timestamp.
+    pub async fn ban(&self, username: String, banned: bool) -> Result<i32> {
+        let brawler_id = self.find_id(username).await?;
+
+        let banned_at = banned.then(|| Utc::now().naive_utc());
+        self.brawler_repository
+            .set_banned_at(brawler_id, banned_at)
+            .await?;
+        Ok(brawler_id)
+    }
+
+    /// Rebuilds the leaderboard stats and awards any badge a brawler has earned
+    /// but missed, e.g. after fixing data by hand. Returns the newly awarded badges.
+    pub async fn recount_stats(&self) -> Result<usize> {
+        self.leaderboard_repository.refresh().await?;
+
+        let brawler_ids = self.brawler_repository.get_ids().await?;
+        let awarded = self.achievement_repository.evaluate(brawler_ids).await?;
+
+        Ok(awarded.len())
+    }
+
+    async fn find_id(&self, username: String) -> Result<i32> {
+        let brawler = self
+            .brawler_repository
+            .find_by_username(username.clone())
+            .await
+            .with_context(|| format!("Brawler {} not found", username))?;
+        Ok(brawler.id)
+    }
+}
@@ -34,6 +34,10 @@ where
             return Err(anyhow::anyhow!("Invalid Password !!"));
         }
 
+        if user.banned_at.is_some() {
+            return Err(anyhow::anyhow!("This brawler is banned"));
+        }
+
         let passport = Passport::new(user.id, user.display_name, user.avatar_url)?;
         Ok(passport)
     }
@@ -1,28 +1,56 @@
 use crate::{
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        entities::brawler_avatar_variants::AddBrawlerAvatarVariantEntity,
+        repositories::{
+            achievement::AchievementRepository, brawlers::BrawlerRepository,
+            image_storage::ImageStorage,
+        },
         value_objects::{
-            base64_img::Base64Img, brawler_model::RegisterBrawlerModel, uploaded_img::UploadedImg,
+            base64_img::Base64Img,
+            brawler_model::{
+                AvatarModel, AvatarVariantModel, PublicProfileModel, RegisterBrawlerModel,
+            },
+            upload_file::UploadFile,
+            uploaded_img::UploadImageOptions,
         },
     },
-    infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::{
+        argon2::hash,
+        image_processing::{self, identicon},
+        jwt::jwt_model::Passport,
+    },
 };
-use anyhow::{Ok, Result};
+use anyhow::Result;
 use std::sync::Arc;
+use tracing::warn;
 
-pub struct BrawlersUseCase<T>
+pub struct BrawlersUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    achievement_repository: Arc<T2>,
+    image_storage: Arc<T3>,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2, T3> BrawlersUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        achievement_repository: Arc<T2>,
+        image_storage: Arc<T3>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            achievement_repository,
+            image_storage,
+        }
     }
 
     pub async fn register(
@@ -40,24 +68,121 @@ where
         Ok(passport)
     }
 
+    pub async fn get_profile(&self, brawler_id: i32) -> Result<PublicProfileModel> {
+        let profile = self.brawler_repository.get_profile(brawler_id).await?;
+
+        let badges = self
+            .achievement_repository
+            .get_awarded(brawler_id)
+            .await?
+            .iter()
+            .map(|(brawler_badge, badge)| brawler_badge.to_model(badge))
+            .collect();
+
+        let avatar_variants = self
+            .brawler_repository
+            .get_avatar_variants(brawler_id)
+            .await?
+            .iter()
+            .map(|variant| variant.to_model())
+            .collect();
+
+        Ok(PublicProfileModel {
+            profile,
+            badges,
+            avatar_variants,
+        })
+    }
+
     pub async fn upload_base64img(
         &self,
         user_id: i32,
         base64string: String,
-    ) -> Result<UploadedImg> {
-        let opt = UploadImageOptions {
-            folder: Some("avatar".to_string()),
-            public_id: Some(user_id.to_string()),
-            transformation: Some("c_scale,w_256".to_string()),
-        };
+    ) -> Result<AvatarModel> {
+        let file = Base64Img::new(base64string)?.into_file();
+
+        self.upload_avatar(user_id, file).await
+    }
+
+    /// Stores square WebP thumbnails of the image instead of the upload itself,
+    /// so no original (and none of its metadata) ever reaches the image store.
+    pub async fn upload_avatar(&self, user_id: i32, file: UploadFile) -> Result<AvatarModel> {
+        let thumbnails =
+            tokio::task::spawn_blocking(move || image_processing::avatar_variants(&file)).await??;
+
+        // A fresh name per upload, so a failed upload never overwrites the current avatar.
+        let version = uuid::Uuid::new_v4().simple().to_string();
+        let mut variants: Vec<AddBrawlerAvatarVariantEntity> = Vec::new();
+        for thumbnail in thumbnails {
+            let opt = UploadImageOptions {
+                folder: Some("avatar".to_string()),
+                public_id: Some(format!("{}_{}_{}", user_id, thumbnail.size, version)),
+                transformation: None,
+            };
 
-        let base64img = Base64Img::new(base64string)?;
+            let uploaded = match self.image_storage.upload(thumbnail.file, opt).await {
+                Ok(uploaded) => uploaded,
+                Err(e) => {
+                    self.delete_files(variants.iter().map(|variant| &variant.public_id))
+                        .await;
+                    return Err(e);
+                }
+            };
+            variants.push(AddBrawlerAvatarVariantEntity {
+                brawler_id: user_id,
+                size: i32::try_from(thumbnail.size)?,
+                url: uploaded.url,
+                public_id: uploaded.public_id,
+            });
+        }
 
-        let uploaded = self
+        let previous = match self
             .brawler_repository
-            .upload_base64img(user_id, base64img, opt)
-            .await?;
+            .update_avatar(user_id, variants.clone())
+            .await
+        {
+            Ok(previous) => previous,
+            Err(e) => {
+                self.delete_files(variants.iter().map(|variant| &variant.public_id))
+                    .await;
+                return Err(e);
+            }
+        };
+        self.delete_files(previous.iter()).await;
+
+        let avatar = variants
+            .iter()
+            .max_by_key(|variant| variant.size)
+            .ok_or_else(|| anyhow::anyhow!("avatar has no variants"))?;
+        Ok(AvatarModel {
+            url: avatar.url.clone(),
+            public_id: avatar.public_id.clone(),
+            variants: variants
+                .iter()
+                .map(|variant| AvatarVariantModel {
+                    size: variant.size,
+                    url: variant.url.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    pub async fn remove_avatar(&self, user_id: i32) -> Result<()> {
+        let public_ids = self.brawler_repository.remove_avatar(user_id).await?;
+        self.delete_files(public_ids.iter()).await;
+        Ok(())
+    }
+
+    pub fn get_default_avatar(&self, brawler_id: i32) -> String {
+        identicon::identicon_svg(brawler_id)
+    }
 
-        Ok(uploaded)
+    /// Files no row points at any more; failing here only leaves an orphan file.
+    async fn delete_files(&self, public_ids: impl Iterator<Item = &String>) {
+        for public_id in public_ids {
+            if let Err(e) = self.image_storage.delete(public_id).await {
+                warn!("Failed to delete {} from the image store: {}", public_id, e);
+            }
+        }
     }
 }
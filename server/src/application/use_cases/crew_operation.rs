@@ -1,35 +1,75 @@
+use crate::config::config_loader::get_outcome_env;
 use crate::domain::{
-    entities::crew_memberships::CrewMemberShips,
+    entities::{
+        activity_events::AddActivityEventEntity, crew_memberships::CrewMemberShips,
+        missions::MissionEntity,
+    },
     repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+        achievement::AchievementRepository, activity::ActivityRepository,
+        crew_operation::CrewOperationRepository, guild::GuildRepository,
+        mission_rating::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+        social::SocialRepository,
+    },
+    value_objects::{
+        activity_model::ActivityKind, mission_model::MissionInvitationModel,
+        mission_role_model::normalize_role, mission_statuses::MissionStatuses,
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 use anyhow::Result;
+use chrono::Utc;
 use std::sync::Arc;
+use tracing::warn;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    mission_rating_repository: Arc<T3>,
+    achievement_repository: Arc<T4>,
+    guild_repository: Arc<T5>,
+    social_repository: Arc<T6>,
+    activity_repository: Arc<T7>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4, T5, T6, T7> CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        mission_rating_repository: Arc<T3>,
+        achievement_repository: Arc<T4>,
+        guild_repository: Arc<T5>,
+        social_repository: Arc<T6>,
+        activity_repository: Arc<T7>,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            mission_rating_repository,
+            achievement_repository,
+            guild_repository,
+            social_repository,
+            activity_repository,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    pub async fn join(&self, mission_id: i32, brawler_id: i32, role: Option<String>) -> Result<()> {
         let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
             .expect("missing value")
             .parse()?;
@@ -42,6 +82,14 @@ where
             ));
         }
 
+        if self
+            .social_repository
+            .is_blocked_between(mission.chief_id, brawler_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You can not join this mission"));
+        }
+
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
@@ -52,18 +100,66 @@ where
         if !mission_status_condition {
             return Err(anyhow::anyhow!("Mission is not joinable"));
         }
-        let crew_count_condition = crew_count < max_crew_per_mission;
+        check_crew_unlocked(&mission)?;
+        let crew_count_condition =
+            crew_count < mission.max_crew.map_or(max_crew_per_mission, i64::from);
         if !crew_count_condition {
             return Err(anyhow::anyhow!("Mission is full"));
         }
 
+        if mission.guild_only
+            && let Some(guild_id) = mission.guild_id
+        {
+            let role = self.guild_repository.get_role(guild_id, brawler_id).await?;
+            if role.is_none() {
+                return Err(anyhow::anyhow!(
+                    "Only members of the owning guild can join this mission"
+                ));
+            }
+        }
+
+        if let Some(min_reputation) = mission.min_reputation {
+            let reputation = self
+                .mission_rating_repository
+                .get_reputation(brawler_id)
+                .await?;
+            // Unrated brawlers are let through so newcomers can earn their first ratings.
+            if reputation.rating_count > 0 && reputation.reputation_score < min_reputation {
+                return Err(anyhow::anyhow!(
+                    "Your reputation is below the minimum for this mission"
+                ));
+            }
+        }
+
+        let role = self.check_role(mission_id, brawler_id, role).await?;
+
         self.crew_operation_repository
             .join(CrewMemberShips {
                 mission_id,
                 brawler_id,
+                role,
             })
             .await?;
 
+        if let Err(e) = self.achievement_repository.evaluate(vec![brawler_id]).await {
+            warn!(
+                "Failed to evaluate achievements of brawler {}: {}",
+                brawler_id, e
+            );
+        }
+
+        if let Err(e) = self
+            .activity_repository
+            .record(AddActivityEventEntity {
+                actor_id: brawler_id,
+                mission_id,
+                kind: ActivityKind::CrewJoined.to_string(),
+            })
+            .await
+        {
+            warn!("Failed to record activity of mission {}: {}", mission_id, e);
+        }
+
         Ok(())
     }
 
@@ -75,13 +171,168 @@ where
         if !leaving_condition {
             return Err(anyhow::anyhow!("Mission is not leavable"));
         }
+        check_crew_unlocked(&mission)?;
         self.crew_operation_repository
             .leave(CrewMemberShips {
                 mission_id,
                 brawler_id,
+                role: None,
             })
             .await?;
 
+        if let Err(e) = self
+            .activity_repository
+            .record(AddActivityEventEntity {
+                actor_id: brawler_id,
+                mission_id,
+                kind: ActivityKind::CrewLeft.to_string(),
+            })
+            .await
+        {
+            warn!("Failed to record activity of mission {}: {}", mission_id, e);
+        }
+
         Ok(())
     }
+
+    /// Swaps the caller to another role of the mission while it is still Open.
+    pub async fn change_role(&self, mission_id: i32, brawler_id: i32, role: String) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.status != MissionStatuses::Open.to_string() {
+            return Err(anyhow::anyhow!(
+                "Roles can only be changed while the mission is Open"
+            ));
+        }
+
+        let crew_ids = self
+            .mission_viewing_repository
+            .get_crew_ids(mission_id)
+            .await?;
+        if !crew_ids.contains(&brawler_id) {
+            return Err(anyhow::anyhow!("You are not in the crew of this mission"));
+        }
+
+        let role = self.check_role(mission_id, brawler_id, Some(role)).await?;
+
+        self.crew_operation_repository
+            .change_role(CrewMemberShips {
+                mission_id,
+                brawler_id,
+                role,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Missions without role slots take no role; missions with slots require
+    /// one that still has a free place. The brawler's own seat is not counted,
+    /// so a swap never competes with itself.
+    async fn check_role(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        role: Option<String>,
+    ) -> Result<Option<String>> {
+        let slots = self
+            .mission_viewing_repository
+            .get_role_slots(mission_id)
+            .await?;
+
+        if slots.is_empty() {
+            if role.is_some() {
+                return Err(anyhow::anyhow!("This mission has no roles to pick from"));
+            }
+            return Ok(None);
+        }
+
+        let role = match role {
+            Some(role) => normalize_role(&role)?,
+            None => return Err(anyhow::anyhow!("Pick a role to join this mission")),
+        };
+        let slot = slots
+            .iter()
+            .find(|slot| slot.role == role)
+            .ok_or_else(|| anyhow::anyhow!("Role {} does not exist in this mission", role))?;
+
+        let memberships = self
+            .mission_viewing_repository
+            .get_crew_memberships(mission_id)
+            .await?;
+        let filled = memberships
+            .iter()
+            .filter(|membership| {
+                membership.brawler_id != brawler_id && membership.role.as_deref() == Some(&role)
+            })
+            .count();
+        if filled >= slot.capacity as usize {
+            return Err(anyhow::anyhow!("Role {} is full", role));
+        }
+
+        Ok(Some(role))
+    }
+
+    pub async fn get_invitations(&self, brawler_id: i32) -> Result<Vec<MissionInvitationModel>> {
+        let invitations = self
+            .crew_operation_repository
+            .get_invitations(brawler_id)
+            .await?;
+
+        let result = invitations
+            .iter()
+            .map(|(invitation, mission)| invitation.to_model(mission))
+            .collect();
+        Ok(result)
+    }
+
+    /// An invitation skips nothing: accepting goes through the same checks as
+    /// a regular join, and the invitation is only consumed once the join works.
+    pub async fn accept_invitation(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        role: Option<String>,
+    ) -> Result<()> {
+        let invitations = self
+            .crew_operation_repository
+            .get_invitations(brawler_id)
+            .await?;
+        let is_invited = invitations
+            .iter()
+            .any(|(invitation, _)| invitation.mission_id == mission_id);
+        if !is_invited {
+            return Err(anyhow::anyhow!("Invitation not found"));
+        }
+
+        self.join(mission_id, brawler_id, role).await?;
+
+        self.crew_operation_repository
+            .remove_invitation(mission_id, brawler_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn decline_invitation(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+        let removed = self
+            .crew_operation_repository
+            .remove_invitation(mission_id, brawler_id)
+            .await?;
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Invitation not found"));
+        }
+        Ok(())
+    }
+}
+
+/// The crew that may overturn a finished mission is the one it finished with,
+/// so nobody joins or leaves while the dispute window is open.
+fn check_crew_unlocked(mission: &MissionEntity) -> Result<()> {
+    let outcome_env = get_outcome_env()?;
+    if mission.is_disputable(outcome_env.dispute_hours, Utc::now().naive_utc()) {
+        return Err(anyhow::anyhow!(
+            "The crew can not change while the outcome may still be disputed"
+        ));
+    }
+    Ok(())
 }
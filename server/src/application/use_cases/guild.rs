@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    entities::guild_invitations::AddGuildInvitationEntity,
+    repositories::{guild::GuildRepository, social::SocialRepository},
+    value_objects::{
+        guild_model::{
+            AddGuildModel, GuildInvitationModel, GuildMemberModel, GuildModel, GuildStatsModel,
+        },
+        guild_roles::GuildRoles,
+    },
+};
+
+pub struct GuildUseCase<T1, T2>
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    guild_repository: Arc<T1>,
+    social_repository: Arc<T2>,
+}
+
+impl<T1, T2> GuildUseCase<T1, T2>
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    pub fn new(guild_repository: Arc<T1>, social_repository: Arc<T2>) -> Self {
+        Self {
+            guild_repository,
+            social_repository,
+        }
+    }
+
+    pub async fn create(&self, owner_id: i32, mut add_guild_model: AddGuildModel) -> Result<i32> {
+        add_guild_model.name = add_guild_model.name.trim().to_string();
+        if add_guild_model.name.len() < 3 || add_guild_model.name.len() > 100 {
+            return Err(anyhow::anyhow!(
+                "Guild name must be between 3 and 100 characters long"
+            ));
+        }
+
+        let result = self
+            .guild_repository
+            .create(add_guild_model.to_entity(owner_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_one(&self, guild_id: i32) -> Result<GuildModel> {
+        let guild = self.guild_repository.get_one(guild_id).await?;
+        let members = self.guild_repository.get_members(guild_id).await?;
+
+        Ok(guild.to_model(members.len() as i64))
+    }
+
+    pub async fn get_members(&self, guild_id: i32) -> Result<Vec<GuildMemberModel>> {
+        let result = self.guild_repository.get_members(guild_id).await?;
+        Ok(result)
+    }
+
+    pub async fn get_stats(&self, guild_id: i32) -> Result<GuildStatsModel> {
+        let result = self.guild_repository.get_stats(guild_id).await?;
+        Ok(result)
+    }
+
+    pub async fn invite(&self, guild_id: i32, inviter_id: i32, brawler_id: i32) -> Result<()> {
+        let inviter_role = self.guild_repository.get_role(guild_id, inviter_id).await?;
+        if !inviter_role.is_some_and(|role| GuildRoles::can_manage(&role)) {
+            return Err(anyhow::anyhow!(
+                "Only the guild owner or officers can invite brawlers"
+            ));
+        }
+
+        let brawler_role = self.guild_repository.get_role(guild_id, brawler_id).await?;
+        if brawler_role.is_some() {
+            return Err(anyhow::anyhow!("Brawler is already a member of this guild"));
+        }
+        if self
+            .social_repository
+            .is_blocked_between(inviter_id, brawler_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You can not invite this brawler"));
+        }
+
+        self.guild_repository
+            .invite(AddGuildInvitationEntity {
+                guild_id,
+                brawler_id,
+                invited_by: inviter_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_invitations(&self, brawler_id: i32) -> Result<Vec<GuildInvitationModel>> {
+        let invitations = self.guild_repository.get_invitations(brawler_id).await?;
+
+        let result = invitations
+            .iter()
+            .map(|(invitation, guild)| invitation.to_model(guild))
+            .collect();
+        Ok(result)
+    }
+
+    pub async fn accept(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        self.guild_repository
+            .accept_invitation(guild_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn decline(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        self.guild_repository
+            .decline_invitation(guild_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn leave(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        let role = self
+            .guild_repository
+            .get_role(guild_id, brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not a member of this guild"))?;
+
+        if role == GuildRoles::Owner.to_string() {
+            return Err(anyhow::anyhow!("The owner can not leave their own guild!!"));
+        }
+
+        self.guild_repository
+            .remove_member(guild_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn change_role(
+        &self,
+        guild_id: i32,
+        actor_id: i32,
+        brawler_id: i32,
+        role: GuildRoles,
+    ) -> Result<()> {
+        if role == GuildRoles::Owner {
+            return Err(anyhow::anyhow!("Guild ownership can not be assigned"));
+        }
+
+        let actor_role = self.guild_repository.get_role(guild_id, actor_id).await?;
+        if actor_role != Some(GuildRoles::Owner.to_string()) {
+            return Err(anyhow::anyhow!("Only the guild owner can change roles"));
+        }
+
+        let brawler_role = self
+            .guild_repository
+            .get_role(guild_id, brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Brawler is not a member of this guild"))?;
+        if brawler_role == GuildRoles::Owner.to_string() {
+            return Err(anyhow::anyhow!("The owner's role can not be changed"));
+        }
+
+        self.guild_repository
+            .update_role(guild_id, brawler_id, role.to_string())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_member(&self, guild_id: i32, actor_id: i32, brawler_id: i32) -> Result<()> {
+        let actor_role = self
+            .guild_repository
+            .get_role(guild_id, actor_id)
+            .await?
+            .unwrap_or_default();
+        let brawler_role = self
+            .guild_repository
+            .get_role(guild_id, brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Brawler is not a member of this guild"))?;
+
+        let removal_condition = if actor_role == GuildRoles::Owner.to_string() {
+            brawler_role != GuildRoles::Owner.to_string()
+        } else if actor_role == GuildRoles::Officer.to_string() {
+            brawler_role == GuildRoles::Member.to_string()
+        } else {
+            false
+        };
+        if !removal_condition {
+            return Err(anyhow::anyhow!("You can not remove this member"));
+        }
+
+        self.guild_repository
+            .remove_member(guild_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::{
+    config::config_loader::get_leaderboard_env,
+    domain::{
+        repositories::leaderboard::LeaderboardRepository,
+        value_objects::leaderboard_model::{LeaderboardEntryModel, LeaderboardFilter, SeasonModel},
+    },
+};
+
+const DEFAULT_LIMIT: i64 = 10;
+const MAX_LIMIT: i64 = 100;
+const ALL_TIME_SEASON_ID: i32 = 0;
+
+pub struct LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    leaderboard_repository: Arc<T>,
+}
+
+impl<T> LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    pub fn new(leaderboard_repository: Arc<T>) -> Self {
+        Self {
+            leaderboard_repository,
+        }
+    }
+
+    pub async fn get_leaderboard(
+        &self,
+        filter: &LeaderboardFilter,
+    ) -> Result<Vec<LeaderboardEntryModel>> {
+        let leaderboard_env = get_leaderboard_env()?;
+
+        let metric = filter.metric.unwrap_or_default();
+        let season_id = filter.season.unwrap_or(ALL_TIME_SEASON_ID);
+        let limit = filter.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        let result = self
+            .leaderboard_repository
+            .get_leaderboard(metric, season_id, leaderboard_env.min_sample, limit)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_seasons(&self) -> Result<Vec<SeasonModel>> {
+        let entities = self.leaderboard_repository.get_seasons().await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+}
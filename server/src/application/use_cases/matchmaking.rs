@@ -0,0 +1,590 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::warn;
+
+use crate::{
+    application::use_cases::{
+        crew_operation::CrewOperationUseCase, mission_management::MissionManagementUseCase,
+    },
+    config::config_loader::get_matchmaking_env,
+    domain::{
+        entities::{matchmaking_tickets::MatchmakingTicketEntity, missions::MissionEntity},
+        repositories::{
+            achievement::AchievementRepository, activity::ActivityRepository,
+            crew_operation::CrewOperationRepository, guild::GuildRepository,
+            image_storage::ImageStorage, matchmaking::MatchmakingRepository,
+            mission_management::MissionManagementRepository,
+            mission_rating::MissionRatingRepository, mission_template::MissionTemplateRepository,
+            mission_viewing::MissionViewingRepository, social::SocialRepository,
+        },
+        value_objects::{
+            matchmaking_model::{EnqueueModel, MatchmakingStatuses, MatchmakingTicketModel},
+            mission_model::{AddMissionModel, normalize_tags},
+            mission_role_model::normalize_role,
+        },
+    },
+};
+
+/// An Open mission as the matcher sees it: places already taken by the crew
+/// or held by pending proposals are not free.
+struct Opening {
+    mission: MissionEntity,
+    crew_ids: Vec<i32>,
+    free: i64,
+    /// Free places per role; empty when the mission has no role slots.
+    free_roles: HashMap<String, i64>,
+}
+
+impl Opening {
+    fn take(&mut self, role: Option<&String>) {
+        self.free -= 1;
+        if let Some(free) = role.and_then(|role| self.free_roles.get_mut(role)) {
+            *free -= 1;
+        }
+    }
+}
+
+pub struct MatchmakingUseCase<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    crew_operation_use_case: Arc<CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7>>,
+    mission_management_use_case: Arc<MissionManagementUseCase<T9, T2, T5, T7, T10, T11>>,
+    mission_viewing_repository: Arc<T2>,
+    mission_rating_repository: Arc<T3>,
+    matchmaking_repository: Arc<T8>,
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+    MatchmakingUseCase<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    pub fn new(
+        crew_operation_use_case: Arc<CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7>>,
+        mission_management_use_case: Arc<MissionManagementUseCase<T9, T2, T5, T7, T10, T11>>,
+        mission_viewing_repository: Arc<T2>,
+        mission_rating_repository: Arc<T3>,
+        matchmaking_repository: Arc<T8>,
+    ) -> Self {
+        Self {
+            crew_operation_use_case,
+            mission_management_use_case,
+            mission_viewing_repository,
+            mission_rating_repository,
+            matchmaking_repository,
+        }
+    }
+
+    pub async fn enqueue(&self, brawler_id: i32, mut enqueue_model: EnqueueModel) -> Result<()> {
+        enqueue_model.category = match enqueue_model.category {
+            Some(category) => normalize_tags(&[category])?.pop(),
+            None => None,
+        };
+        enqueue_model.role = enqueue_model
+            .role
+            .as_deref()
+            .map(normalize_role)
+            .transpose()?;
+        if let Some(min_reputation) = enqueue_model.min_reputation
+            && !(0.0..=5.0).contains(&min_reputation)
+        {
+            return Err(anyhow::anyhow!(
+                "Minimum reputation must be between 0 and 5"
+            ));
+        }
+
+        self.matchmaking_repository
+            .enqueue(enqueue_model.to_entity(brawler_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_ticket(&self, brawler_id: i32) -> Result<MatchmakingTicketModel> {
+        let matchmaking_env = get_matchmaking_env()?;
+
+        let ticket = self
+            .matchmaking_repository
+            .get_ticket(brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not in the matchmaking queue"))?;
+
+        Ok(ticket.to_model(Duration::seconds(matchmaking_env.proposal_timeout_secs)))
+    }
+
+    pub async fn leave(&self, brawler_id: i32) -> Result<()> {
+        let ticket = self
+            .matchmaking_repository
+            .get_ticket(brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not in the matchmaking queue"))?;
+
+        let removed = self.matchmaking_repository.remove(brawler_id).await?;
+        if removed == 0 {
+            return Err(anyhow::anyhow!("You are not in the matchmaking queue"));
+        }
+
+        if let Err(e) = self.abandon_if_chief(&ticket).await {
+            warn!(
+                "Failed to abandon the crew of brawler {}: {}",
+                brawler_id, e
+            );
+        }
+        Ok(())
+    }
+
+    /// Accepting goes through the regular join, so capacity, status and
+    /// reputation rules stay in `CrewOperationUseCase`. A failed join puts the
+    /// brawler back in the queue. The chief of a matchmade crew accepts to
+    /// lead it, and nobody can join before they did.
+    pub async fn accept(&self, brawler_id: i32) -> Result<i32> {
+        let (ticket, mission_id) = self.get_proposal(brawler_id).await?;
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id == brawler_id {
+            self.matchmaking_repository.remove(brawler_id).await?;
+            return Ok(mission_id);
+        }
+        let chief_ticket = self
+            .matchmaking_repository
+            .get_ticket(mission.chief_id)
+            .await?;
+        if chief_ticket.is_some_and(|chief_ticket| chief_ticket.mission_id == Some(mission_id)) {
+            return Err(anyhow::anyhow!(
+                "Waiting for the chief of this mission to accept"
+            ));
+        }
+
+        let slots = self
+            .mission_viewing_repository
+            .get_role_slots(mission_id)
+            .await?;
+        let role = if slots.is_empty() { None } else { ticket.role };
+
+        if let Err(e) = self
+            .crew_operation_use_case
+            .join(mission_id, brawler_id, role)
+            .await
+        {
+            if let Err(e) = self.matchmaking_repository.requeue(brawler_id).await {
+                warn!("Failed to requeue brawler {}: {}", brawler_id, e);
+            }
+            return Err(e);
+        }
+
+        self.matchmaking_repository.remove(brawler_id).await?;
+        Ok(mission_id)
+    }
+
+    pub async fn decline(&self, brawler_id: i32) -> Result<()> {
+        let (ticket, _) = self.get_proposal(brawler_id).await?;
+        self.withdraw(&ticket).await
+    }
+
+    /// One matcher round: expired proposals go back to the queue, queued
+    /// brawlers are proposed to Open missions with a free place, and whoever
+    /// is left is grouped into new crews. Returns how many proposals were made.
+    pub async fn match_queued(&self, now: NaiveDateTime) -> Result<usize> {
+        let matchmaking_env = get_matchmaking_env()?;
+        let timeout = Duration::seconds(matchmaking_env.proposal_timeout_secs);
+
+        let (expired, proposed): (Vec<_>, Vec<_>) = self
+            .matchmaking_repository
+            .get_by_status(&MatchmakingStatuses::Proposed.to_string())
+            .await?
+            .into_iter()
+            .partition(|ticket| ticket.proposed_at.is_some_and(|at| at < now - timeout));
+        for ticket in expired.iter() {
+            if let Err(e) = self.withdraw(ticket).await {
+                warn!(
+                    "Failed to expire the proposal of brawler {}: {}",
+                    ticket.brawler_id, e
+                );
+            }
+        }
+
+        let queued = self
+            .matchmaking_repository
+            .get_by_status(&MatchmakingStatuses::Queued.to_string())
+            .await?;
+        if queued.is_empty() {
+            return Ok(0);
+        }
+
+        let mut openings = Vec::new();
+        for mission in self.matchmaking_repository.get_open_missions().await? {
+            let mission_id = mission.id;
+            if awaits_chief(&mission, &proposed) {
+                continue;
+            }
+            match self.opening_of(mission, &proposed).await {
+                Ok(opening) => openings.push(opening),
+                Err(e) => warn!("Failed to load openings of mission {}: {}", mission_id, e),
+            }
+        }
+
+        let mut brawler_ids: Vec<i32> = queued.iter().map(|ticket| ticket.brawler_id).collect();
+        brawler_ids.extend(openings.iter().map(|opening| opening.mission.chief_id));
+        let reputations = self.reputations_of(brawler_ids).await?;
+
+        let mut proposals = 0;
+        let mut waiting = Vec::new();
+        for ticket in queued.into_iter() {
+            let opening = openings
+                .iter_mut()
+                .find(|opening| fits(&ticket, opening, &reputations));
+            let Some(opening) = opening else {
+                waiting.push(ticket);
+                continue;
+            };
+
+            if self
+                .matchmaking_repository
+                .propose(ticket.brawler_id, opening.mission.id)
+                .await?
+            {
+                opening.take(ticket.role.as_ref());
+                proposals += 1;
+            }
+        }
+
+        proposals += self
+            .form_crews(waiting, matchmaking_env.crew_size, &reputations)
+            .await?;
+
+        Ok(proposals)
+    }
+
+    async fn get_proposal(&self, brawler_id: i32) -> Result<(MatchmakingTicketEntity, i32)> {
+        let matchmaking_env = get_matchmaking_env()?;
+
+        let ticket = self
+            .matchmaking_repository
+            .get_ticket(brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("You are not in the matchmaking queue"))?;
+
+        let (Some(mission_id), Some(proposed_at)) = (ticket.mission_id, ticket.proposed_at) else {
+            return Err(anyhow::anyhow!("No pending matchmaking proposal"));
+        };
+
+        let expires_at = proposed_at + Duration::seconds(matchmaking_env.proposal_timeout_secs);
+        if Utc::now().naive_utc() > expires_at {
+            self.withdraw(&ticket).await?;
+            return Err(anyhow::anyhow!("Matchmaking proposal has expired"));
+        }
+
+        Ok((ticket, mission_id))
+    }
+
+    /// Puts a proposed ticket back in the queue.
+    async fn withdraw(&self, ticket: &MatchmakingTicketEntity) -> Result<()> {
+        self.matchmaking_repository
+            .requeue(ticket.brawler_id)
+            .await?;
+
+        if let Err(e) = self.abandon_if_chief(ticket).await {
+            warn!(
+                "Failed to abandon the crew of brawler {}: {}",
+                ticket.brawler_id, e
+            );
+        }
+        Ok(())
+    }
+
+    /// A matchmade mission whose chief declined, left or let the proposal
+    /// expire is trashed. Nobody can have joined it yet, and the crew still
+    /// waiting on it goes back to the queue.
+    async fn abandon_if_chief(&self, ticket: &MatchmakingTicketEntity) -> Result<()> {
+        let Some(mission_id) = ticket.mission_id else {
+            return Ok(());
+        };
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != ticket.brawler_id {
+            return Ok(());
+        }
+
+        self.mission_management_use_case
+            .remove(mission_id, mission.chief_id)
+            .await?;
+
+        let proposed = self
+            .matchmaking_repository
+            .get_by_status(&MatchmakingStatuses::Proposed.to_string())
+            .await?;
+        for ticket in proposed
+            .iter()
+            .filter(|ticket| ticket.mission_id == Some(mission_id))
+        {
+            if let Err(e) = self.matchmaking_repository.requeue(ticket.brawler_id).await {
+                warn!("Failed to requeue brawler {}: {}", ticket.brawler_id, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn opening_of(
+        &self,
+        mission: MissionEntity,
+        proposed: &[MatchmakingTicketEntity],
+    ) -> Result<Opening> {
+        let max_crew_per_mission: i64 = std::env::var("MAX_CREW_PER_MISSION")
+            .expect("missing value")
+            .parse()?;
+
+        let memberships = self
+            .mission_viewing_repository
+            .get_crew_memberships(mission.id)
+            .await?;
+        let slots = self
+            .mission_viewing_repository
+            .get_role_slots(mission.id)
+            .await?;
+        let pending: Vec<&MatchmakingTicketEntity> = proposed
+            .iter()
+            .filter(|ticket| ticket.mission_id == Some(mission.id))
+            .collect();
+
+        let max_crew = mission.max_crew.map_or(max_crew_per_mission, i64::from);
+        let free = max_crew - memberships.len() as i64 - pending.len() as i64;
+
+        let free_roles = slots
+            .iter()
+            .map(|slot| {
+                let taken = memberships
+                    .iter()
+                    .filter(|membership| membership.role.as_ref() == Some(&slot.role))
+                    .count()
+                    + pending
+                        .iter()
+                        .filter(|ticket| ticket.role.as_ref() == Some(&slot.role))
+                        .count();
+                (slot.role.clone(), i64::from(slot.capacity) - taken as i64)
+            })
+            .collect();
+
+        Ok(Opening {
+            crew_ids: memberships
+                .iter()
+                .map(|membership| membership.brawler_id)
+                .collect(),
+            mission,
+            free,
+            free_roles,
+        })
+    }
+
+    /// Reputation of each brawler, or `None` while they are unrated.
+    async fn reputations_of(&self, brawler_ids: Vec<i32>) -> Result<HashMap<i32, Option<f64>>> {
+        let mut brawler_ids = brawler_ids;
+        brawler_ids.sort_unstable();
+        brawler_ids.dedup();
+
+        let mut result: HashMap<i32, Option<f64>> = brawler_ids
+            .iter()
+            .map(|&brawler_id| (brawler_id, None))
+            .collect();
+        let reputations = self
+            .mission_rating_repository
+            .get_reputations(brawler_ids)
+            .await?;
+        for reputation in reputations.into_iter() {
+            let score = (reputation.rating_count > 0).then_some(reputation.reputation_score);
+            result.insert(reputation.brawler_id, score);
+        }
+        Ok(result)
+    }
+
+    /// Brawlers still waiting who share a category are grouped into a new
+    /// mission led by whoever has waited longest. Everyone, the chief
+    /// included, gets a proposal for it.
+    async fn form_crews(
+        &self,
+        waiting: Vec<MatchmakingTicketEntity>,
+        crew_size: usize,
+        reputations: &HashMap<i32, Option<f64>>,
+    ) -> Result<usize> {
+        if crew_size < 2 {
+            return Ok(0);
+        }
+
+        let mut groups: BTreeMap<String, Vec<MatchmakingTicketEntity>> = BTreeMap::new();
+        for ticket in waiting.into_iter() {
+            if let Some(category) = ticket.category.clone() {
+                groups.entry(category).or_default().push(ticket);
+            }
+        }
+
+        let mut proposals = 0;
+        for (category, mut remaining) in groups.into_iter() {
+            while remaining.len() >= crew_size {
+                let chief = remaining.remove(0);
+                let chief_reputation = reputations.get(&chief.brawler_id).copied().flatten();
+
+                let mut crew = Vec::new();
+                let mut rest = Vec::new();
+                for ticket in remaining.into_iter() {
+                    if crew.len() + 1 < crew_size && accepts_chief(&ticket, chief_reputation) {
+                        crew.push(ticket);
+                    } else {
+                        rest.push(ticket);
+                    }
+                }
+                remaining = rest;
+
+                if crew.len() + 1 < crew_size {
+                    remaining.extend(crew);
+                    remaining.sort_by_key(|ticket| ticket.created_at);
+                    continue;
+                }
+
+                match self.form_crew(&category, &chief, &crew).await {
+                    Ok(proposed) => proposals += proposed,
+                    Err(e) => warn!(
+                        "Failed to form a {} crew led by brawler {}: {}",
+                        category, chief.brawler_id, e
+                    ),
+                }
+            }
+        }
+
+        Ok(proposals)
+    }
+
+    async fn form_crew(
+        &self,
+        category: &str,
+        chief: &MatchmakingTicketEntity,
+        crew: &[MatchmakingTicketEntity],
+    ) -> Result<usize> {
+        let mission_id = self
+            .mission_management_use_case
+            .add(
+                chief.brawler_id,
+                AddMissionModel {
+                    name: format!("Matchmade {} crew", category),
+                    description: None,
+                    min_reputation: None,
+                    guild_id: None,
+                    guild_only: false,
+                    max_crew: None,
+                    tags: vec![category.to_string()],
+                    consensus_outcome: false,
+                    require_objectives: false,
+                },
+            )
+            .await?;
+
+        if !self
+            .matchmaking_repository
+            .propose(chief.brawler_id, mission_id)
+            .await?
+        {
+            // The chief left the queue in the meantime.
+            self.mission_management_use_case
+                .remove(mission_id, chief.brawler_id)
+                .await?;
+            return Ok(0);
+        }
+
+        let mut proposals = 1;
+        for ticket in crew.iter() {
+            if self
+                .matchmaking_repository
+                .propose(ticket.brawler_id, mission_id)
+                .await?
+            {
+                proposals += 1;
+            }
+        }
+        Ok(proposals)
+    }
+}
+
+fn fits(
+    ticket: &MatchmakingTicketEntity,
+    opening: &Opening,
+    reputations: &HashMap<i32, Option<f64>>,
+) -> bool {
+    let mission = &opening.mission;
+    if opening.free <= 0
+        || mission.chief_id == ticket.brawler_id
+        || opening.crew_ids.contains(&ticket.brawler_id)
+        || ticket.declined_mission_ids.contains(&mission.id)
+    {
+        return false;
+    }
+
+    if let Some(category) = &ticket.category
+        && !mission.tags.contains(category)
+    {
+        return false;
+    }
+
+    if !opening.free_roles.is_empty() {
+        let free = ticket
+            .role
+            .as_ref()
+            .and_then(|role| opening.free_roles.get(role))
+            .copied()
+            .unwrap_or(0);
+        if free <= 0 {
+            return false;
+        }
+    }
+
+    let chief_reputation = reputations.get(&mission.chief_id).copied().flatten();
+    if !accepts_chief(ticket, chief_reputation) {
+        return false;
+    }
+
+    // Same leniency as the join rule: unrated brawlers are let through.
+    let reputation = reputations.get(&ticket.brawler_id).copied().flatten();
+    if let (Some(min_reputation), Some(reputation)) = (mission.min_reputation, reputation)
+        && reputation < min_reputation
+    {
+        return false;
+    }
+
+    true
+}
+
+/// A matchmade mission is not open to anyone else until its chief accepted.
+fn awaits_chief(mission: &MissionEntity, proposed: &[MatchmakingTicketEntity]) -> bool {
+    proposed.iter().any(|ticket| {
+        ticket.brawler_id == mission.chief_id && ticket.mission_id == Some(mission.id)
+    })
+}
+
+/// A brawler asking for a minimum reputation only accepts rated chiefs.
+fn accepts_chief(ticket: &MatchmakingTicketEntity, chief_reputation: Option<f64>) -> bool {
+    match ticket.min_reputation {
+        Some(min_reputation) => chief_reputation.is_some_and(|score| score >= min_reputation),
+        None => true,
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::warn;
+
+use crate::domain::{
+    entities::mission_attachments::AddMissionAttachmentEntity,
+    repositories::{
+        image_storage::ImageStorage, mission_attachment::MissionAttachmentRepository,
+        mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        base64_img::{ATTACHMENT_MIME_TYPES, Base64Img},
+        mission_attachment_model::{MissionAttachmentModel, UploadAttachmentModel},
+        uploaded_img::{UploadImageOptions, UploadedImg},
+    },
+};
+
+const MAX_ATTACHMENTS_PER_MISSION: i64 = 10;
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+pub struct MissionAttachmentUseCase<T1, T2, T3>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    mission_attachment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    image_storage: Arc<T3>,
+}
+
+impl<T1, T2, T3> MissionAttachmentUseCase<T1, T2, T3>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    pub fn new(
+        mission_attachment_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        image_storage: Arc<T3>,
+    ) -> Self {
+        Self {
+            mission_attachment_repository,
+            mission_viewing_repository,
+            image_storage,
+        }
+    }
+
+    pub async fn upload_banner(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        base64string: String,
+    ) -> Result<UploadedImg> {
+        self.check_chief(mission_id, chief_id).await?;
+
+        // A fresh name per upload, so the new file can be dropped again without
+        // touching the current banner.
+        let version = uuid::Uuid::new_v4().simple().to_string();
+        let opt = UploadImageOptions {
+            folder: Some("mission_banner".to_string()),
+            public_id: Some(format!("{}_{}", mission_id, version)),
+            transformation: Some("c_limit,w_1600".to_string()),
+        };
+
+        let file = Base64Img::new(base64string)?.into_file();
+
+        let uploaded = self.image_storage.upload(file, opt).await?;
+
+        let previous = match self
+            .mission_attachment_repository
+            .set_banner(mission_id, uploaded.clone())
+            .await
+        {
+            Ok(previous) => previous,
+            Err(e) => {
+                self.delete_file(&uploaded.public_id).await;
+                return Err(e);
+            }
+        };
+        if let Some(previous) = previous.filter(|previous| *previous != uploaded.public_id) {
+            self.delete_file(&previous).await;
+        }
+
+        Ok(uploaded)
+    }
+
+    pub async fn remove_banner(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+        self.check_chief(mission_id, chief_id).await?;
+
+        let public_id = self
+            .mission_attachment_repository
+            .remove_banner(mission_id)
+            .await?;
+        self.delete_file(&public_id).await;
+        Ok(())
+    }
+
+    pub async fn get_all(&self, mission_id: i32) -> Result<Vec<MissionAttachmentModel>> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let entities = self
+            .mission_attachment_repository
+            .get_by_mission(mission_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    /// The chief and the crew can attach images or PDFs to a mission.
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        upload_attachment_model: UploadAttachmentModel,
+    ) -> Result<i32> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != brawler_id {
+            let crew_ids = self
+                .mission_viewing_repository
+                .get_crew_ids(mission_id)
+                .await?;
+            if !crew_ids.contains(&brawler_id) {
+                return Err(anyhow::anyhow!(
+                    "Only the chief and crew can add attachments to this mission"
+                ));
+            }
+        }
+
+        let file_name = upload_attachment_model
+            .file_name
+            .map(|file_name| file_name.trim().to_string())
+            .filter(|file_name| !file_name.is_empty());
+        if file_name
+            .as_ref()
+            .is_some_and(|file_name| file_name.chars().count() > MAX_FILE_NAME_LENGTH)
+        {
+            return Err(anyhow::anyhow!(
+                "File name must be at most {} characters long",
+                MAX_FILE_NAME_LENGTH
+            ));
+        }
+
+        let attachment_count = self.mission_attachment_repository.count(mission_id).await?;
+        if attachment_count >= MAX_ATTACHMENTS_PER_MISSION {
+            return Err(anyhow::anyhow!(
+                "A mission can have at most {} attachments",
+                MAX_ATTACHMENTS_PER_MISSION
+            ));
+        }
+
+        let file = Base64Img::with_types(
+            upload_attachment_model.base64_string,
+            &ATTACHMENT_MIME_TYPES,
+        )?
+        .into_file();
+        let opt = UploadImageOptions {
+            folder: Some(format!("mission_attachments/{}", mission_id)),
+            public_id: None,
+            transformation: None,
+        };
+
+        let mime_type = file.mime_type().to_string();
+        let size_bytes = i32::try_from(file.size_bytes())?;
+
+        let uploaded = self.image_storage.upload(file, opt).await?;
+
+        let add_mission_attachment_entity = AddMissionAttachmentEntity {
+            mission_id,
+            uploaded_by: brawler_id,
+            file_name,
+            mime_type,
+            size_bytes,
+            url: uploaded.url,
+            public_id: uploaded.public_id.clone(),
+        };
+
+        match self
+            .mission_attachment_repository
+            .add(add_mission_attachment_entity)
+            .await
+        {
+            Ok(attachment_id) => Ok(attachment_id),
+            Err(e) => {
+                // Do not leave an orphan file in the store when the row is missing.
+                self.delete_file(&uploaded.public_id).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// An attachment can be removed by whoever uploaded it or by the chief.
+    pub async fn remove(&self, mission_id: i32, attachment_id: i32, brawler_id: i32) -> Result<()> {
+        let attachment = self
+            .mission_attachment_repository
+            .get_one(attachment_id)
+            .await?;
+        if attachment.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Attachment not found"));
+        }
+
+        if attachment.uploaded_by != brawler_id {
+            let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+            if mission.chief_id != brawler_id {
+                return Err(anyhow::anyhow!(
+                    "Only the uploader or the chief can remove this attachment"
+                ));
+            }
+        }
+
+        let public_id = self
+            .mission_attachment_repository
+            .remove(attachment_id)
+            .await?;
+        self.delete_file(&public_id).await;
+        Ok(())
+    }
+
+    async fn check_chief(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the chief can change the banner of this mission"
+            ));
+        }
+        Ok(())
+    }
+
+    /// The row is already gone, so a failure here only leaves an orphan file.
+    async fn delete_file(&self, public_id: &str) {
+        if let Err(e) = self.image_storage.delete(public_id).await {
+            warn!("Failed to delete {} from the image store: {}", public_id, e);
+        }
+    }
+}
@@ -1,59 +1,130 @@
 use std::sync::Arc;
 
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::warn;
+
+use crate::config::config_loader::get_trash_env;
 use crate::domain::{
+    entities::{
+        activity_events::AddActivityEventEntity, mission_invitations::AddMissionInvitationEntity,
+    },
     repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+        activity::ActivityRepository, guild::GuildRepository, image_storage::ImageStorage,
+        mission_management::MissionManagementRepository,
+        mission_template::MissionTemplateRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        activity_model::ActivityKind,
+        guild_roles::GuildRoles,
+        mission_model::{
+            AddMissionModel, CloneMissionModel, DeletedMissionModel, EditMissionModel,
+            check_max_crew, normalize_tags,
+        },
+        mission_role_model::{SetRoleSlotsModel, normalize_role_slots},
+        mission_statuses::MissionStatuses,
+        mission_template_model::{AddMissionTemplateModel, MissionTemplateModel},
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
 };
 
-pub struct MissionManagementUseCase<T1, T2>
+pub struct MissionManagementUseCase<T1, T2, T3, T4, T5, T6>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    guild_repository: Arc<T3>,
+    activity_repository: Arc<T4>,
+    mission_template_repository: Arc<T5>,
+    image_storage: Arc<T6>,
 }
 
 use anyhow::Result;
 
-impl<T1, T2> MissionManagementUseCase<T1, T2>
+impl<T1, T2, T3, T4, T5, T6> MissionManagementUseCase<T1, T2, T3, T4, T5, T6>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        guild_repository: Arc<T3>,
+        activity_repository: Arc<T4>,
+        mission_template_repository: Arc<T5>,
+        image_storage: Arc<T6>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            guild_repository,
+            activity_repository,
+            mission_template_repository,
+            image_storage,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
+    pub async fn add(&self, chief_id: i32, mut add_mission_model: AddMissionModel) -> Result<i32> {
         if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len() < 3 {
             return Err(anyhow::anyhow!(
                 "Mission name must be least 4 characters long"
             ));
         }
+        check_max_crew(add_mission_model.max_crew)?;
+        add_mission_model.tags = normalize_tags(&add_mission_model.tags)?;
+
+        match add_mission_model.guild_id {
+            Some(guild_id) => {
+                let role = self.guild_repository.get_role(guild_id, chief_id).await?;
+                if !role.is_some_and(|role| GuildRoles::can_manage(&role)) {
+                    return Err(anyhow::anyhow!(
+                        "Only the guild owner or officers can create guild missions"
+                    ));
+                }
+            }
+            None if add_mission_model.guild_only => {
+                return Err(anyhow::anyhow!(
+                    "Guild-only missions must belong to a guild"
+                ));
+            }
+            None => {}
+        }
 
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
 
-        let result = self
+        let mission_id = self
             .mission_management_repository
             .add(insert_mission_entity)
             .await?;
 
-        Ok(result)
+        if let Err(e) = self
+            .activity_repository
+            .record(AddActivityEventEntity {
+                actor_id: chief_id,
+                mission_id,
+                kind: ActivityKind::MissionCreated.to_string(),
+            })
+            .await
+        {
+            warn!("Failed to record activity of mission {}: {}", mission_id, e);
+        }
+
+        Ok(mission_id)
     }
 
     pub async fn edit(
         &self,
         mission_id: i32,
         chief_id: i32,
+        expected_version: Option<i32>,
         mut edit_mission_model: EditMissionModel,
     ) -> Result<i32> {
         if let Some(name) = edit_mission_model.name {
@@ -67,6 +138,10 @@ where
                 edit_mission_model.name = Some(name.trim().to_string())
             }
         }
+        check_max_crew(edit_mission_model.max_crew.flatten())?;
+        if let Some(tags) = &edit_mission_model.tags {
+            edit_mission_model.tags = Some(normalize_tags(tags)?);
+        }
 
         let crew_count = self
             .mission_viewing_repository
@@ -78,11 +153,11 @@ where
             ));
         }
 
-        let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        let edit_mission_entity = edit_mission_model.to_entity();
 
         let result = self
             .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
+            .edit(mission_id, chief_id, expected_version, edit_mission_entity)
             .await?;
 
         Ok(result)
@@ -104,4 +179,281 @@ where
             .await?;
         Ok(())
     }
+
+    /// Replaces the role slots of an Open mission. An empty list removes roles
+    /// altogether; brawlers already in the crew must still fit the new slots.
+    pub async fn set_role_slots(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        set_role_slots_model: SetRoleSlotsModel,
+    ) -> Result<()> {
+        let slots = normalize_role_slots(&set_role_slots_model.slots)?;
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the chief can set roles of this mission"
+            ));
+        }
+        if mission.status != MissionStatuses::Open.to_string() {
+            return Err(anyhow::anyhow!(
+                "Roles can only be set while the mission is Open"
+            ));
+        }
+
+        let max_crew_per_mission: i64 = std::env::var("MAX_CREW_PER_MISSION")
+            .expect("missing value")
+            .parse()?;
+        let max_crew = mission.max_crew.map_or(max_crew_per_mission, i64::from);
+        let total_capacity: i64 = slots.iter().map(|slot| i64::from(slot.capacity)).sum();
+        if total_capacity > max_crew {
+            return Err(anyhow::anyhow!(
+                "Role capacities add up to {} but the mission takes at most {} brawlers",
+                total_capacity,
+                max_crew
+            ));
+        }
+
+        let memberships = self
+            .mission_viewing_repository
+            .get_crew_memberships(mission_id)
+            .await?;
+        if slots.is_empty() {
+            if memberships
+                .iter()
+                .any(|membership| membership.role.is_some())
+            {
+                return Err(anyhow::anyhow!(
+                    "Roles can not be removed while brawlers in the crew hold one"
+                ));
+            }
+        } else {
+            for membership in memberships.iter() {
+                let slot = membership
+                    .role
+                    .as_ref()
+                    .and_then(|role| slots.iter().find(|slot| &slot.role == role));
+                let Some(slot) = slot else {
+                    return Err(anyhow::anyhow!(
+                        "Brawler {} in the crew has no role among the new slots",
+                        membership.brawler_id
+                    ));
+                };
+                let filled = memberships
+                    .iter()
+                    .filter(|other| other.role.as_deref() == Some(slot.role.as_str()))
+                    .count() as i64;
+                if filled > i64::from(slot.capacity) {
+                    return Err(anyhow::anyhow!(
+                        "Role {} already has {} brawlers in the crew",
+                        slot.role,
+                        filled
+                    ));
+                }
+            }
+        }
+
+        let entities = slots
+            .iter()
+            .map(|slot| slot.to_entity(mission_id))
+            .collect();
+
+        self.mission_management_repository
+            .set_role_slots(mission_id, entities)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_trash(&self, chief_id: i32) -> Result<Vec<DeletedMissionModel>> {
+        let retention = Duration::days(get_trash_env()?.retention_days);
+        let since = Utc::now().naive_utc() - retention;
+
+        let entities = self
+            .mission_management_repository
+            .get_deleted(chief_id, since)
+            .await?;
+
+        let result = entities
+            .iter()
+            .filter_map(|entity| entity.to_deleted_model(retention))
+            .collect();
+        Ok(result)
+    }
+
+    pub async fn restore(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        let retention = Duration::days(get_trash_env()?.retention_days);
+        let since = Utc::now().naive_utc() - retention;
+
+        let result = self
+            .mission_management_repository
+            .restore(mission_id, chief_id, since)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn purge_deleted(&self, now: NaiveDateTime) -> Result<usize> {
+        let retention = Duration::days(get_trash_env()?.retention_days);
+
+        let (purged, public_ids) = self
+            .mission_management_repository
+            .purge_deleted(now - retention)
+            .await?;
+
+        // Files go once the rows are gone; a failure only leaves an orphan file.
+        for public_id in public_ids.iter() {
+            if let Err(e) = self.image_storage.delete(public_id).await {
+                warn!("Failed to delete {} from the image store: {}", public_id, e);
+            }
+        }
+
+        Ok(purged)
+    }
+
+    pub async fn save_template(
+        &self,
+        owner_id: i32,
+        mut add_mission_template_model: AddMissionTemplateModel,
+    ) -> Result<i32> {
+        add_mission_template_model.name = add_mission_template_model.name.trim().to_string();
+        if add_mission_template_model.name.len() < 3 {
+            return Err(anyhow::anyhow!(
+                "Template name must be least 4 characters long"
+            ));
+        }
+        check_max_crew(add_mission_template_model.max_crew)?;
+        add_mission_template_model.tags = normalize_tags(&add_mission_template_model.tags)?;
+
+        let result = self
+            .mission_template_repository
+            .create(add_mission_template_model.to_entity(owner_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_mission_as_template(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the chief can save this mission as a template"
+            ));
+        }
+
+        self.save_template(
+            chief_id,
+            AddMissionTemplateModel {
+                name: mission.name,
+                description: mission.description,
+                max_crew: mission.max_crew,
+                tags: mission.tags,
+            },
+        )
+        .await
+    }
+
+    pub async fn get_templates(&self, owner_id: i32) -> Result<Vec<MissionTemplateModel>> {
+        let entities = self
+            .mission_template_repository
+            .get_by_owner(owner_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    pub async fn remove_template(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        self.mission_template_repository
+            .remove(template_id, owner_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn add_from_template(&self, template_id: i32, chief_id: i32) -> Result<i32> {
+        let template = self
+            .mission_template_repository
+            .get_one(template_id)
+            .await?;
+        if template.owner_id != chief_id {
+            return Err(anyhow::anyhow!("Template not found"));
+        }
+
+        self.add(
+            chief_id,
+            AddMissionModel {
+                name: template.name,
+                description: template.description,
+                min_reputation: None,
+                guild_id: None,
+                guild_only: false,
+                max_crew: template.max_crew,
+                tags: template.tags,
+                consensus_outcome: false,
+                require_objectives: false,
+            },
+        )
+        .await
+    }
+
+    /// Clones a finished mission into a fresh Open one. With `reinvite_crew` the
+    /// previous crew gets an invitation instead of being joined outright, since
+    /// they may not want to run it again.
+    pub async fn clone_mission(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        clone_mission_model: CloneMissionModel,
+    ) -> Result<i32> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can clone this mission"));
+        }
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        if !is_finished {
+            return Err(anyhow::anyhow!(
+                "Only completed or failed missions can be cloned"
+            ));
+        }
+
+        let new_mission_id = self
+            .add(
+                chief_id,
+                AddMissionModel {
+                    name: mission.name,
+                    description: mission.description,
+                    min_reputation: mission.min_reputation,
+                    guild_id: mission.guild_id,
+                    guild_only: mission.guild_only,
+                    max_crew: mission.max_crew,
+                    tags: mission.tags,
+                    consensus_outcome: mission.consensus_outcome,
+                    require_objectives: mission.require_objectives,
+                },
+            )
+            .await?;
+
+        if clone_mission_model.reinvite_crew {
+            let crew_ids = self
+                .mission_viewing_repository
+                .get_crew_ids(mission_id)
+                .await?;
+            let invitations = crew_ids
+                .into_iter()
+                .map(|brawler_id| AddMissionInvitationEntity {
+                    mission_id: new_mission_id,
+                    brawler_id,
+                    invited_by: chief_id,
+                })
+                .collect::<Vec<_>>();
+
+            if !invitations.is_empty() {
+                self.mission_management_repository
+                    .invite_crew(invitations)
+                    .await?;
+            }
+        }
+
+        Ok(new_mission_id)
+    }
 }
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::{mission_objectives::MissionObjectiveEntity, missions::MissionEntity},
+    repositories::{
+        mission_objective::MissionObjectiveRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel, MissionObjectiveModel,
+            ReorderObjectivesModel,
+        },
+        mission_statuses::MissionStatuses,
+    },
+};
+
+const MAX_OBJECTIVES_PER_MISSION: usize = 50;
+const MAX_OBJECTIVE_TITLE_LENGTH: usize = 255;
+
+pub struct MissionObjectiveUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_objective_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionObjectiveUseCase<T1, T2>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_objective_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_objective_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    pub async fn get_all(&self, mission_id: i32) -> Result<Vec<MissionObjectiveModel>> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let entities = self
+            .mission_objective_repository
+            .get_by_mission(mission_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        mut add_mission_objective_model: AddMissionObjectiveModel,
+    ) -> Result<i32> {
+        add_mission_objective_model.title = check_title(&add_mission_objective_model.title)?;
+        self.get_managed_mission(mission_id, chief_id).await?;
+
+        let objectives = self
+            .mission_objective_repository
+            .get_by_mission(mission_id)
+            .await?;
+        if objectives.len() >= MAX_OBJECTIVES_PER_MISSION {
+            return Err(anyhow::anyhow!(
+                "A mission can have at most {} objectives",
+                MAX_OBJECTIVES_PER_MISSION
+            ));
+        }
+
+        // The repository places it after the current last objective.
+        let result = self
+            .mission_objective_repository
+            .add(add_mission_objective_model.to_entity(mission_id, 0))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        chief_id: i32,
+        mut edit_mission_objective_model: EditMissionObjectiveModel,
+    ) -> Result<i32> {
+        if let Some(title) = &edit_mission_objective_model.title {
+            edit_mission_objective_model.title = Some(check_title(title)?);
+        }
+        self.get_managed_mission(mission_id, chief_id).await?;
+        self.get_objective(mission_id, objective_id).await?;
+
+        let result = self
+            .mission_objective_repository
+            .edit(objective_id, edit_mission_objective_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, mission_id: i32, objective_id: i32, chief_id: i32) -> Result<()> {
+        self.get_managed_mission(mission_id, chief_id).await?;
+        self.get_objective(mission_id, objective_id).await?;
+
+        self.mission_objective_repository
+            .remove(objective_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn reorder(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reorder_objectives_model: ReorderObjectivesModel,
+    ) -> Result<()> {
+        self.get_managed_mission(mission_id, chief_id).await?;
+
+        let objectives = self
+            .mission_objective_repository
+            .get_by_mission(mission_id)
+            .await?;
+
+        let mut current_ids = objectives
+            .iter()
+            .map(|objective| objective.id)
+            .collect::<Vec<_>>();
+        let mut requested_ids = reorder_objectives_model.objective_ids.clone();
+        current_ids.sort_unstable();
+        requested_ids.sort_unstable();
+        if current_ids != requested_ids {
+            return Err(anyhow::anyhow!(
+                "Order must list every objective of the mission exactly once"
+            ));
+        }
+
+        self.mission_objective_repository
+            .reorder(mission_id, reorder_objectives_model.objective_ids)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn complete(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        brawler_id: i32,
+    ) -> Result<()> {
+        self.get_running_mission(mission_id, brawler_id).await?;
+
+        let objective = self.get_objective(mission_id, objective_id).await?;
+        if objective.completed_at.is_some() {
+            return Err(anyhow::anyhow!("Objective is already done"));
+        }
+
+        self.mission_objective_repository
+            .set_completed(objective_id, Some(brawler_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn reopen(&self, mission_id: i32, objective_id: i32, brawler_id: i32) -> Result<()> {
+        self.get_running_mission(mission_id, brawler_id).await?;
+
+        let objective = self.get_objective(mission_id, objective_id).await?;
+        if objective.completed_at.is_none() {
+            return Err(anyhow::anyhow!("Objective is not done yet"));
+        }
+
+        self.mission_objective_repository
+            .set_completed(objective_id, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Objectives are defined by the chief until the mission is finished.
+    async fn get_managed_mission(&self, mission_id: i32, chief_id: i32) -> Result<MissionEntity> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(anyhow::anyhow!(
+                "Only the chief can manage objectives of this mission"
+            ));
+        }
+
+        let is_active = mission.status == MissionStatuses::Open.to_string()
+            || mission.status == MissionStatuses::InProgress.to_string();
+        if !is_active {
+            return Err(anyhow::anyhow!("Mission is already finished"));
+        }
+
+        Ok(mission)
+    }
+
+    /// Objectives are ticked off by the chief or crew while the mission is in progress.
+    async fn get_running_mission(&self, mission_id: i32, brawler_id: i32) -> Result<MissionEntity> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.status != MissionStatuses::InProgress.to_string() {
+            return Err(anyhow::anyhow!("Mission is not in progress"));
+        }
+
+        if mission.chief_id != brawler_id {
+            let crew_ids = self
+                .mission_viewing_repository
+                .get_crew_ids(mission_id)
+                .await?;
+            if !crew_ids.contains(&brawler_id) {
+                return Err(anyhow::anyhow!(
+                    "Only the chief and crew of this mission can update objectives"
+                ));
+            }
+        }
+
+        Ok(mission)
+    }
+
+    async fn get_objective(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+    ) -> Result<MissionObjectiveEntity> {
+        let objective = self
+            .mission_objective_repository
+            .get_one(objective_id)
+            .await?;
+        if objective.mission_id != mission_id {
+            return Err(anyhow::anyhow!("Objective not found"));
+        }
+        Ok(objective)
+    }
+}
+
+fn check_title(title: &str) -> Result<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(anyhow::anyhow!("Objective title can not be empty"));
+    }
+    if title.chars().count() > MAX_OBJECTIVE_TITLE_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Objective title must be at most {} characters long",
+            MAX_OBJECTIVE_TITLE_LENGTH
+        ));
+    }
+    Ok(title.to_string())
+}
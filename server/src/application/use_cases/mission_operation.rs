@@ -1,31 +1,66 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::warn;
 
-use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    config::config_loader::{get_outcome_env, get_progression_env},
+    domain::{
+        entities::{
+            activity_events::AddActivityEventEntity,
+            missions::MissionEntity,
+            points_ledger::{AddPointsLedgerEntity, PointsLedgerEntity},
+        },
+        repositories::{
+            achievement::AchievementRepository, activity::ActivityRepository,
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository, points_ledger::PointsLedgerRepository,
+        },
+        value_objects::{
+            activity_model::ActivityKind,
+            mission_outcome_model::{AddOutcomeVoteModel, OutcomeTallyModel},
+            mission_statuses::{MissionStatuses, StatusConflict},
+            progression_model::PointsSource,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
-pub struct MissionOperationUseCase<T1, T2>
+pub struct MissionOperationUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
 {
     mission_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    achievement_repository: Arc<T3>,
+    points_ledger_repository: Arc<T4>,
+    activity_repository: Arc<T5>,
 }
 
-impl<T1, T2> MissionOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4, T5> MissionOperationUseCase<T1, T2, T3, T4, T5>
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        mission_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        achievement_repository: Arc<T3>,
+        points_ledger_repository: Arc<T4>,
+        activity_repository: Arc<T5>,
+    ) -> Self {
         Self {
             mission_operation_repository,
             mission_viewing_repository,
+            achievement_repository,
+            points_ledger_repository,
+            activity_repository,
         }
     }
 
@@ -46,7 +81,7 @@ where
 
         let update_condition = is_status_open_or_fail
             && crew_count > 0
-            && crew_count < max_crew_per_mission
+            && crew_count < mission.max_crew.map_or(max_crew_per_mission, i64::from)
             && mission.chief_id == chief_id;
         if !update_condition {
             return Err(anyhow::anyhow!("Invalid condition to change stages!"));
@@ -56,6 +91,10 @@ where
             .mission_operation_repository
             .to_progress(mission_id, chief_id)
             .await?;
+
+        self.record_activity(mission_id, chief_id, ActivityKind::MissionStarted)
+            .await;
+
         Ok(result)
     }
     pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -66,11 +105,22 @@ where
         if !update_condition {
             return Err(anyhow::anyhow!("Invalid condition to change stages!"));
         }
+        if mission.consensus_outcome {
+            return Err(anyhow::anyhow!(
+                "Outcome of this mission is decided by crew vote"
+            ));
+        }
+        self.check_objectives_done(&mission).await?;
         let result = self
             .mission_operation_repository
             .to_completed(mission_id, chief_id)
             .await?;
 
+        self.on_finished(mission_id, chief_id, PointsSource::MissionCompleted)
+            .await;
+        self.record_activity(mission_id, chief_id, ActivityKind::MissionCompleted)
+            .await;
+
         Ok(result)
     }
     pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
@@ -81,11 +131,504 @@ where
         if !update_condition {
             return Err(anyhow::anyhow!("Invalid condition to change stages!"));
         }
+        if mission.consensus_outcome {
+            return Err(anyhow::anyhow!(
+                "Outcome of this mission is decided by crew vote"
+            ));
+        }
         let result = self
             .mission_operation_repository
             .to_failed(mission_id, chief_id)
             .await?;
 
+        self.on_finished(mission_id, chief_id, PointsSource::MissionFailed)
+            .await;
+        self.record_activity(mission_id, chief_id, ActivityKind::MissionFailed)
+            .await;
+
         Ok(result)
     }
+
+    /// Crew members vote on the outcome. A consensus mission finishes once one
+    /// outcome reaches the quorum; a finished mission is overturned only when the
+    /// whole crew votes against the chief's decision within the dispute window.
+    pub async fn vote(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        add_outcome_vote_model: AddOutcomeVoteModel,
+    ) -> Result<OutcomeTallyModel> {
+        let outcome = add_outcome_vote_model.outcome.clone();
+        if outcome != MissionStatuses::Completed && outcome != MissionStatuses::Failed {
+            return Err(anyhow::anyhow!("Outcome must be Completed or Failed"));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew_ids = self
+            .mission_viewing_repository
+            .get_crew_ids(mission_id)
+            .await?;
+        if !crew_ids.contains(&brawler_id) {
+            return Err(anyhow::anyhow!(
+                "Only crew members of this mission can vote"
+            ));
+        }
+
+        let outcome_env = get_outcome_env()?;
+        let is_in_progress = mission.status == MissionStatuses::InProgress.to_string();
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+
+        if is_in_progress && !mission.consensus_outcome {
+            return Err(anyhow::anyhow!(
+                "Outcome of this mission is decided by its chief"
+            ));
+        }
+        if is_finished {
+            if mission.outcome_overridden_at.is_some() {
+                return Err(anyhow::anyhow!(
+                    "Outcome of this mission has already been overridden"
+                ));
+            }
+            let dispute_closed_at = mission
+                .finished_at
+                .map(|finished_at| finished_at + Duration::hours(outcome_env.dispute_hours));
+            if dispute_closed_at.is_none_or(|closed_at| Utc::now().naive_utc() > closed_at) {
+                return Err(anyhow::anyhow!("Dispute window for this mission is closed"));
+            }
+        }
+        if !is_in_progress && !is_finished {
+            return Err(anyhow::anyhow!("Mission has not started yet"));
+        }
+        if outcome == MissionStatuses::Completed {
+            self.check_objectives_done(&mission).await?;
+        }
+
+        self.mission_operation_repository
+            .vote_outcome(add_outcome_vote_model.to_entity(mission_id, brawler_id))
+            .await?;
+
+        let tally = self
+            .tally(&mission, &crew_ids, outcome_env.quorum_percent)
+            .await?;
+        let outcome_votes = match outcome {
+            MissionStatuses::Completed => tally.completed_votes,
+            _ => tally.failed_votes,
+        };
+
+        let result = if is_in_progress && outcome_votes >= tally.required_votes {
+            self.finish_by_consensus(&mission, outcome).await
+        } else if is_finished
+            && mission.status != outcome.to_string()
+            && outcome_votes == tally.crew_count
+        {
+            self.override_outcome(&mission, outcome).await
+        } else {
+            Ok(())
+        };
+        // A concurrent vote has already applied the outcome; this vote still counts.
+        match result {
+            Err(e) if e.is::<StatusConflict>() => {}
+            result => result?,
+        }
+
+        self.get_tally(mission_id).await
+    }
+
+    async fn check_objectives_done(&self, mission: &MissionEntity) -> Result<()> {
+        if !mission.require_objectives {
+            return Ok(());
+        }
+
+        let pending = self
+            .mission_viewing_repository
+            .pending_required_objectives(mission.id)
+            .await?;
+        if pending > 0 {
+            return Err(anyhow::anyhow!(
+                "{} required objectives are not done yet",
+                pending
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn get_tally(&self, mission_id: i32) -> Result<OutcomeTallyModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let crew_ids = self
+            .mission_viewing_repository
+            .get_crew_ids(mission_id)
+            .await?;
+        let outcome_env = get_outcome_env()?;
+
+        self.tally(&mission, &crew_ids, outcome_env.quorum_percent)
+            .await
+    }
+
+    async fn tally(
+        &self,
+        mission: &MissionEntity,
+        crew_ids: &[i32],
+        quorum_percent: i64,
+    ) -> Result<OutcomeTallyModel> {
+        let votes = self
+            .mission_operation_repository
+            .get_outcome_votes(mission.id)
+            .await?;
+
+        // Votes of brawlers who have since left the crew no longer count.
+        let count_of = |outcome: MissionStatuses| {
+            votes
+                .iter()
+                .filter(|vote| crew_ids.contains(&vote.brawler_id))
+                .filter(|vote| vote.outcome == outcome.to_string())
+                .count() as i64
+        };
+
+        let crew_count = crew_ids.len() as i64;
+        let required_votes = (crew_count * quorum_percent.clamp(1, 100) + 99) / 100;
+
+        Ok(OutcomeTallyModel {
+            mission_id: mission.id,
+            status: mission.status.clone(),
+            consensus_outcome: mission.consensus_outcome,
+            crew_count,
+            required_votes: required_votes.max(1),
+            completed_votes: count_of(MissionStatuses::Completed),
+            failed_votes: count_of(MissionStatuses::Failed),
+        })
+    }
+
+    async fn finish_by_consensus(
+        &self,
+        mission: &MissionEntity,
+        outcome: MissionStatuses,
+    ) -> Result<()> {
+        let (source, kind) = match outcome {
+            MissionStatuses::Completed => {
+                self.mission_operation_repository
+                    .to_completed(mission.id, mission.chief_id)
+                    .await?;
+                (
+                    PointsSource::MissionCompleted,
+                    ActivityKind::MissionCompleted,
+                )
+            }
+            _ => {
+                self.mission_operation_repository
+                    .to_failed(mission.id, mission.chief_id)
+                    .await?;
+                (PointsSource::MissionFailed, ActivityKind::MissionFailed)
+            }
+        };
+
+        self.on_finished(mission.id, mission.chief_id, source).await;
+        self.record_activity(mission.id, mission.chief_id, kind)
+            .await;
+
+        Ok(())
+    }
+
+    async fn override_outcome(
+        &self,
+        mission: &MissionEntity,
+        outcome: MissionStatuses,
+    ) -> Result<()> {
+        let (previous, previous_source, source, kind) = match outcome {
+            MissionStatuses::Completed => (
+                MissionStatuses::Failed,
+                PointsSource::MissionFailed,
+                PointsSource::MissionCompleted,
+                ActivityKind::MissionCompleted,
+            ),
+            _ => (
+                MissionStatuses::Completed,
+                PointsSource::MissionCompleted,
+                PointsSource::MissionFailed,
+                ActivityKind::MissionFailed,
+            ),
+        };
+
+        self.mission_operation_repository
+            .override_outcome(mission.id, previous, outcome)
+            .await?;
+
+        if let Err(e) = self.reverse_points(mission, previous_source).await {
+            warn!("Failed to reverse points of mission {}: {}", mission.id, e);
+        }
+        self.on_finished(mission.id, mission.chief_id, source).await;
+        self.record_activity(mission.id, mission.chief_id, kind)
+            .await;
+
+        Ok(())
+    }
+
+    /// Only the run being overridden is reversed; `mission` still carries its `finished_at`.
+    async fn reverse_points(&self, mission: &MissionEntity, source: PointsSource) -> Result<()> {
+        let finished_at = mission
+            .finished_at
+            .ok_or_else(|| anyhow::anyhow!("Mission {} has not finished", mission.id))?;
+        let awarded = self
+            .points_ledger_repository
+            .get_by_source(&source.to_string(), mission.id, finished_at)
+            .await?;
+
+        let entries = reversal_entries(mission.id, finished_at, &awarded);
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        self.points_ledger_repository.append(entries).await?;
+        Ok(())
+    }
+
+    /// Rewards never block the status change itself; they are logged and can be
+    /// replayed, since both the ledger and the badges are idempotent.
+    async fn on_finished(&self, mission_id: i32, chief_id: i32, source: PointsSource) {
+        let crew_ids = self
+            .mission_viewing_repository
+            .get_crew_ids(mission_id)
+            .await
+            .unwrap_or_default();
+
+        if let Err(e) = self
+            .award_points(mission_id, chief_id, &crew_ids, source)
+            .await
+        {
+            warn!("Failed to award points of mission {}: {}", mission_id, e);
+        }
+
+        let mut brawler_ids = crew_ids;
+        brawler_ids.push(chief_id);
+        if let Err(e) = self.achievement_repository.evaluate(brawler_ids).await {
+            warn!(
+                "Failed to evaluate achievements of mission {}: {}",
+                mission_id, e
+            );
+        }
+    }
+
+    async fn record_activity(&self, mission_id: i32, chief_id: i32, kind: ActivityKind) {
+        if let Err(e) = self
+            .activity_repository
+            .record(AddActivityEventEntity {
+                actor_id: chief_id,
+                mission_id,
+                kind: kind.to_string(),
+            })
+            .await
+        {
+            warn!("Failed to record activity of mission {}: {}", mission_id, e);
+        }
+    }
+
+    async fn award_points(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        crew_ids: &[i32],
+        source: PointsSource,
+    ) -> Result<()> {
+        let progression_env = get_progression_env()?;
+        let (chief_xp, crew_xp) = match source {
+            PointsSource::MissionCompleted => (
+                progression_env.chief_completed_xp,
+                progression_env.crew_completed_xp,
+            ),
+            PointsSource::MissionFailed => (
+                progression_env.chief_failed_xp,
+                progression_env.crew_failed_xp,
+            ),
+            PointsSource::Correction | PointsSource::OutcomeReversal => return Ok(()),
+        };
+
+        let finished_at = self
+            .mission_viewing_repository
+            .get_one(mission_id)
+            .await?
+            .finished_at
+            .ok_or_else(|| anyhow::anyhow!("Mission {} has not finished", mission_id))?;
+
+        let entries = award_entries(
+            mission_id,
+            finished_at,
+            chief_id,
+            crew_ids,
+            &source,
+            (chief_xp, crew_xp),
+        );
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        self.points_ledger_repository.append(entries).await?;
+        Ok(())
+    }
+}
+
+/// Rows for one finished run of a mission. The ledger takes each event once
+/// per run, so a restarted mission that finishes again is awarded again.
+fn award_entries(
+    mission_id: i32,
+    finished_at: NaiveDateTime,
+    chief_id: i32,
+    crew_ids: &[i32],
+    source: &PointsSource,
+    (chief_xp, crew_xp): (i32, i32),
+) -> Vec<AddPointsLedgerEntity> {
+    let entry = |brawler_id: i32, points: i32| AddPointsLedgerEntity {
+        brawler_id,
+        source: source.to_string(),
+        source_id: Some(mission_id),
+        points,
+        note: None,
+        issued_by: None,
+        source_at: Some(finished_at),
+    };
+
+    std::iter::once(entry(chief_id, chief_xp))
+        .chain(crew_ids.iter().map(|crew_id| entry(*crew_id, crew_xp)))
+        .filter(|entry| entry.points != 0)
+        .collect()
+}
+
+fn reversal_entries(
+    mission_id: i32,
+    finished_at: NaiveDateTime,
+    awarded: &[PointsLedgerEntity],
+) -> Vec<AddPointsLedgerEntity> {
+    awarded
+        .iter()
+        .filter(|entry| entry.points != 0)
+        .map(|entry| AddPointsLedgerEntity {
+            brawler_id: entry.brawler_id,
+            source: PointsSource::OutcomeReversal.to_string(),
+            source_id: Some(mission_id),
+            points: -entry.points,
+            note: Some("Outcome overridden by crew vote".to_string()),
+            issued_by: None,
+            source_at: Some(finished_at),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    const MISSION: i32 = 7;
+    const CHIEF: i32 = 1;
+    const CREW: [i32; 2] = [2, 3];
+    const COMPLETED_XP: (i32, i32) = (100, 50);
+    const FAILED_XP: (i32, i32) = (20, 10);
+
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 19)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    /// Appends the way the database does: a row repeating an event key is dropped.
+    fn append(ledger: &mut Vec<PointsLedgerEntity>, entries: Vec<AddPointsLedgerEntity>) {
+        for entry in entries {
+            let duplicate = ledger.iter().any(|row| {
+                row.brawler_id == entry.brawler_id
+                    && row.source == entry.source
+                    && row.source_id == entry.source_id
+                    && row.source_at == entry.source_at
+            });
+            if duplicate {
+                continue;
+            }
+            ledger.push(PointsLedgerEntity {
+                id: ledger.len() as i32 + 1,
+                brawler_id: entry.brawler_id,
+                source: entry.source,
+                source_id: entry.source_id,
+                points: entry.points,
+                note: entry.note,
+                issued_by: entry.issued_by,
+                created_at: at(0),
+                source_at: entry.source_at,
+            });
+        }
+    }
+
+    fn finish(
+        ledger: &mut Vec<PointsLedgerEntity>,
+        finished_at: NaiveDateTime,
+        source: PointsSource,
+    ) {
+        let xp = match source {
+            PointsSource::MissionCompleted => COMPLETED_XP,
+            _ => FAILED_XP,
+        };
+        let entries = award_entries(MISSION, finished_at, CHIEF, &CREW, &source, xp);
+        append(ledger, entries);
+    }
+
+    fn override_to_failed(ledger: &mut Vec<PointsLedgerEntity>, finished_at: NaiveDateTime) {
+        let awarded = ledger
+            .iter()
+            .filter(|row| row.source == PointsSource::MissionCompleted.to_string())
+            .filter(|row| row.source_at == Some(finished_at))
+            .cloned()
+            .collect::<Vec<_>>();
+        append(ledger, reversal_entries(MISSION, finished_at, &awarded));
+        finish(ledger, finished_at, PointsSource::MissionFailed);
+    }
+
+    fn total(ledger: &[PointsLedgerEntity], brawler_id: i32) -> i32 {
+        ledger
+            .iter()
+            .filter(|row| row.brawler_id == brawler_id)
+            .map(|row| row.points)
+            .sum()
+    }
+
+    #[test]
+    fn restart_after_override_is_awarded_again() {
+        let mut ledger = Vec::new();
+
+        finish(&mut ledger, at(10), PointsSource::MissionCompleted);
+        override_to_failed(&mut ledger, at(10));
+        assert_eq!(total(&ledger, CHIEF), 20);
+        assert_eq!(total(&ledger, CREW[0]), 10);
+
+        // The chief restarts the mission and this time it really completes.
+        finish(&mut ledger, at(12), PointsSource::MissionCompleted);
+        assert_eq!(total(&ledger, CHIEF), 120);
+        assert_eq!(total(&ledger, CREW[0]), 60);
+
+        // Replaying the rewards of a run changes nothing.
+        finish(&mut ledger, at(12), PointsSource::MissionCompleted);
+        assert_eq!(total(&ledger, CREW[0]), 60);
+    }
+
+    #[test]
+    fn every_failed_run_pays_out() {
+        let mut ledger = Vec::new();
+
+        finish(&mut ledger, at(10), PointsSource::MissionFailed);
+        finish(&mut ledger, at(12), PointsSource::MissionFailed);
+
+        assert_eq!(total(&ledger, CHIEF), 40);
+        assert_eq!(total(&ledger, CREW[1]), 20);
+    }
+
+    #[test]
+    fn override_only_reverses_its_own_run() {
+        let mut ledger = Vec::new();
+
+        finish(&mut ledger, at(10), PointsSource::MissionCompleted);
+        override_to_failed(&mut ledger, at(10));
+        finish(&mut ledger, at(12), PointsSource::MissionCompleted);
+        override_to_failed(&mut ledger, at(12));
+
+        assert_eq!(total(&ledger, CHIEF), 40);
+        assert_eq!(total(&ledger, CREW[0]), 20);
+    }
 }
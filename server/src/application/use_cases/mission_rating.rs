@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::{
+    config::config_loader::get_rating_env,
+    domain::{
+        repositories::{
+            mission_rating::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+            social::SocialRepository,
+        },
+        value_objects::{
+            mission_rating_model::{AddMissionRatingModel, MissionRatingModel, ReputationModel},
+            mission_statuses::MissionStatuses,
+        },
+    },
+};
+
+pub struct MissionRatingUseCase<T1, T2, T3>
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: SocialRepository + Send + Sync,
+{
+    mission_rating_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    social_repository: Arc<T3>,
+}
+
+impl<T1, T2, T3> MissionRatingUseCase<T1, T2, T3>
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: SocialRepository + Send + Sync,
+{
+    pub fn new(
+        mission_rating_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        social_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            mission_rating_repository,
+            mission_viewing_repository,
+            social_repository,
+        }
+    }
+
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        mut add_mission_rating_model: AddMissionRatingModel,
+    ) -> Result<i32> {
+        if !(1..=5).contains(&add_mission_rating_model.score) {
+            return Err(anyhow::anyhow!("Score must be between 1 and 5"));
+        }
+        if add_mission_rating_model.ratee_id == rater_id {
+            return Err(anyhow::anyhow!("You can not rate yourself!!"));
+        }
+        if let Some(comment) = add_mission_rating_model.comment {
+            let comment = comment.trim().to_string();
+            add_mission_rating_model.comment = (!comment.is_empty()).then_some(comment);
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let is_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        let finished_at = match mission.finished_at {
+            Some(finished_at) if is_finished => finished_at,
+            _ => return Err(anyhow::anyhow!("Mission is not finished yet")),
+        };
+
+        let rating_env = get_rating_env()?;
+        let window_closed_at = finished_at + Duration::hours(rating_env.window_hours);
+        if Utc::now().naive_utc() > window_closed_at {
+            return Err(anyhow::anyhow!("Rating window for this mission is closed"));
+        }
+
+        let mut participants = self
+            .mission_viewing_repository
+            .get_crew_ids(mission_id)
+            .await?;
+        participants.push(mission.chief_id);
+
+        let participation_condition = participants.contains(&rater_id)
+            && participants.contains(&add_mission_rating_model.ratee_id);
+        if !participation_condition {
+            return Err(anyhow::anyhow!(
+                "Only the chief and crew of this mission can rate each other"
+            ));
+        }
+
+        if self
+            .social_repository
+            .is_blocked_between(rater_id, add_mission_rating_model.ratee_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You can not rate this brawler"));
+        }
+
+        let add_mission_rating_entity = add_mission_rating_model.to_entity(mission_id, rater_id);
+
+        let result = self
+            .mission_rating_repository
+            .rate(add_mission_rating_entity)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_received(&self, brawler_id: i32) -> Result<Vec<MissionRatingModel>> {
+        let entities = self
+            .mission_rating_repository
+            .get_received(brawler_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    pub async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        let result = self
+            .mission_rating_repository
+            .get_reputation(brawler_id)
+            .await?;
+        Ok(result)
+    }
+}
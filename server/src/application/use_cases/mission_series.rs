@@ -0,0 +1,404 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::warn;
+
+use crate::{
+    config::config_loader::get_scheduler_env,
+    domain::{
+        entities::{
+            activity_events::AddActivityEventEntity,
+            mission_series::{
+                AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity,
+                weekdays_to_db,
+            },
+            missions::AddMissionEntity,
+        },
+        repositories::{
+            activity::ActivityRepository, guild::GuildRepository,
+            mission_series::MissionSeriesRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            activity_model::ActivityKind,
+            guild_roles::GuildRoles,
+            mission_model::{MissionModel, check_max_crew, normalize_tags},
+            mission_series_model::{
+                AddMissionSeriesModel, EditMissionSeriesModel, MissionSeriesModel,
+                RecurrenceFrequency, RecurrenceRule,
+            },
+            mission_statuses::MissionStatuses,
+        },
+    },
+};
+
+pub struct MissionSeriesUseCase<T1, T2, T3, T4>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    mission_series_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    guild_repository: Arc<T3>,
+    activity_repository: Arc<T4>,
+}
+
+impl<T1, T2, T3, T4> MissionSeriesUseCase<T1, T2, T3, T4>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    pub fn new(
+        mission_series_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        guild_repository: Arc<T3>,
+        activity_repository: Arc<T4>,
+    ) -> Self {
+        Self {
+            mission_series_repository,
+            mission_viewing_repository,
+            guild_repository,
+            activity_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        chief_id: i32,
+        mut add_mission_series_model: AddMissionSeriesModel,
+    ) -> Result<i32> {
+        add_mission_series_model.name = add_mission_series_model.name.trim().to_string();
+        if add_mission_series_model.name.len() < 3 {
+            return Err(anyhow::anyhow!(
+                "Mission name must be least 4 characters long"
+            ));
+        }
+        check_max_crew(add_mission_series_model.max_crew)?;
+        add_mission_series_model.tags = normalize_tags(&add_mission_series_model.tags)?;
+
+        match add_mission_series_model.guild_id {
+            Some(guild_id) => {
+                let role = self.guild_repository.get_role(guild_id, chief_id).await?;
+                if !role.is_some_and(|role| GuildRoles::can_manage(&role)) {
+                    return Err(anyhow::anyhow!(
+                        "Only the guild owner or officers can create guild missions"
+                    ));
+                }
+            }
+            None if add_mission_series_model.guild_only => {
+                return Err(anyhow::anyhow!(
+                    "Guild-only missions must belong to a guild"
+                ));
+            }
+            None => {}
+        }
+
+        let rule = add_mission_series_model.to_rule();
+        check_rule(&rule)?;
+        let next_occurrence_at = rule.first_from(Utc::now().naive_utc(), 0);
+        if next_occurrence_at.is_none() {
+            return Err(anyhow::anyhow!(
+                "Recurrence rule does not produce any occurrence"
+            ));
+        }
+
+        let result = self
+            .mission_series_repository
+            .create(AddMissionSeriesEntity {
+                chief_id,
+                name: add_mission_series_model.name,
+                description: add_mission_series_model.description,
+                max_crew: add_mission_series_model.max_crew,
+                tags: add_mission_series_model.tags,
+                min_reputation: add_mission_series_model.min_reputation,
+                guild_id: add_mission_series_model.guild_id,
+                guild_only: add_mission_series_model.guild_only,
+                frequency: rule.frequency.to_string(),
+                weekdays: weekdays_to_db(&rule.weekdays),
+                starts_at: rule.starts_at,
+                ends_at: rule.ends_at,
+                max_occurrences: rule.max_occurrences,
+                next_occurrence_at,
+            })
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_one(&self, series_id: i32) -> Result<MissionSeriesModel> {
+        let entity = self.mission_series_repository.get_one(series_id).await?;
+        Ok(entity.to_model())
+    }
+
+    pub async fn get_mine(&self, chief_id: i32) -> Result<Vec<MissionSeriesModel>> {
+        let entities = self
+            .mission_series_repository
+            .get_by_chief(chief_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    pub async fn get_occurrences(&self, series_id: i32) -> Result<Vec<MissionModel>> {
+        let entities = self
+            .mission_series_repository
+            .get_occurrences(series_id)
+            .await?;
+
+        let mut result = Vec::new();
+
+        for entity in entities.into_iter() {
+            let crew_count = self
+                .mission_viewing_repository
+                .crew_counting(entity.id)
+                .await
+                .unwrap_or(0);
+
+            let mut mission = entity.to_model(crew_count);
+            mission.progress = self
+                .mission_viewing_repository
+                .objective_progress(entity.id)
+                .await
+                .unwrap_or_default();
+            result.push(mission);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        series_id: i32,
+        chief_id: i32,
+        mut edit_mission_series_model: EditMissionSeriesModel,
+    ) -> Result<()> {
+        let series = self.get_active(series_id, chief_id).await?;
+
+        if let Some(name) = edit_mission_series_model.name {
+            let name = name.trim().to_string();
+            if name.len() < 3 {
+                return Err(anyhow::anyhow!(
+                    "Mission name must be least 4 characters long"
+                ));
+            }
+            edit_mission_series_model.name = Some(name);
+        }
+        check_max_crew(edit_mission_series_model.max_crew)?;
+        if let Some(tags) = &edit_mission_series_model.tags {
+            edit_mission_series_model.tags = Some(normalize_tags(tags)?);
+        }
+
+        let mut rule = series.to_rule();
+        if let Some(weekdays) = &edit_mission_series_model.weekdays {
+            rule.weekdays = weekdays.clone();
+        }
+        if let Some(ends_at) = edit_mission_series_model.ends_at {
+            rule.ends_at = Some(ends_at);
+        }
+        if let Some(max_occurrences) = edit_mission_series_model.max_occurrences {
+            rule.max_occurrences = Some(max_occurrences);
+        }
+        check_rule(&rule)?;
+
+        let next_occurrence_at = if rule != series.to_rule() {
+            Some(self.next_for_edited_rule(&series, &rule).await?)
+        } else {
+            None
+        };
+
+        let edited = self
+            .mission_series_repository
+            .edit(
+                series_id,
+                series.next_occurrence_at,
+                EditMissionSeriesEntity {
+                    name: edit_mission_series_model.name,
+                    description: edit_mission_series_model.description,
+                    max_crew: edit_mission_series_model.max_crew,
+                    tags: edit_mission_series_model.tags,
+                    weekdays: edit_mission_series_model
+                        .weekdays
+                        .as_deref()
+                        .map(weekdays_to_db),
+                    ends_at: edit_mission_series_model.ends_at,
+                    max_occurrences: edit_mission_series_model.max_occurrences,
+                    next_occurrence_at,
+                },
+            )
+            .await?;
+        if !edited {
+            // Either the series was cancelled or the scheduler moved it on meanwhile.
+            self.get_active(series_id, chief_id).await?;
+            return Err(anyhow::anyhow!(
+                "Upcoming occurrence has just been created, try again"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Occurrences before the upcoming one were already created or skipped, and
+    /// occurrences in the past are never back-filled, so the edited rule picks up
+    /// from whichever is later.
+    async fn next_for_edited_rule(
+        &self,
+        series: &MissionSeriesEntity,
+        rule: &RecurrenceRule,
+    ) -> Result<Option<NaiveDateTime>> {
+        let now = Utc::now().naive_utc();
+
+        let after = match series.next_occurrence_at {
+            Some(upcoming) => now.max(upcoming - Duration::seconds(1)),
+            // An exhausted series resumes after its last created occurrence.
+            None => self
+                .mission_series_repository
+                .get_occurrences(series.id)
+                .await?
+                .iter()
+                .filter_map(|mission| mission.scheduled_for)
+                .max()
+                .map_or(now, |last| now.max(last)),
+        };
+
+        Ok(rule.next_after(after, series.occurrence_count))
+    }
+
+    /// Cancelling a series stops future occurrences; missions that were
+    /// already created stay as they are.
+    pub async fn cancel(&self, series_id: i32, chief_id: i32) -> Result<()> {
+        self.get_active(series_id, chief_id).await?;
+        self.mission_series_repository.cancel(series_id).await?;
+        Ok(())
+    }
+
+    /// Cancels only the upcoming occurrence before it is created.
+    pub async fn skip_next(&self, series_id: i32, chief_id: i32) -> Result<()> {
+        let series = self.get_active(series_id, chief_id).await?;
+        let Some(occurrence) = series.next_occurrence_at else {
+            return Err(anyhow::anyhow!("Series has no upcoming occurrence"));
+        };
+
+        let next_occurrence_at = series
+            .to_rule()
+            .next_after(occurrence, series.occurrence_count + 1);
+
+        let skipped = self
+            .mission_series_repository
+            .skip(series_id, occurrence, next_occurrence_at)
+            .await?;
+        if !skipped {
+            return Err(anyhow::anyhow!(
+                "Upcoming occurrence has already been created"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Creates every occurrence due within the lead window as an Open mission.
+    /// Each series is handled on its own so one failure does not hold up the rest.
+    pub async fn materialize_due(&self, now: NaiveDateTime) -> Result<usize> {
+        let scheduler_env = get_scheduler_env()?;
+        let until = now + Duration::hours(scheduler_env.series_lead_hours);
+
+        let due = self.mission_series_repository.get_due(until).await?;
+
+        let mut created = 0;
+        for series in due.into_iter() {
+            match self.materialize(&series).await {
+                Ok(true) => created += 1,
+                Ok(false) => {}
+                Err(e) => warn!("Failed to materialize mission series {}: {}", series.id, e),
+            }
+        }
+
+        Ok(created)
+    }
+
+    async fn materialize(&self, series: &MissionSeriesEntity) -> Result<bool> {
+        let Some(occurrence) = series.next_occurrence_at else {
+            return Ok(false);
+        };
+
+        let next_occurrence_at = series
+            .to_rule()
+            .next_after(occurrence, series.occurrence_count + 1);
+
+        let add_mission_entity = AddMissionEntity {
+            chief_id: series.chief_id,
+            name: series.name.clone(),
+            status: MissionStatuses::Open.to_string(),
+            description: series.description.clone(),
+            min_reputation: series.min_reputation,
+            guild_id: series.guild_id,
+            guild_only: series.guild_only,
+            max_crew: series.max_crew,
+            tags: series.tags.clone(),
+            series_id: Some(series.id),
+            scheduled_for: Some(occurrence),
+            consensus_outcome: false,
+            require_objectives: false,
+        };
+
+        let Some(mission_id) = self
+            .mission_series_repository
+            .materialize(
+                series.id,
+                occurrence,
+                next_occurrence_at,
+                add_mission_entity,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if let Err(e) = self
+            .activity_repository
+            .record(AddActivityEventEntity {
+                actor_id: series.chief_id,
+                mission_id,
+                kind: ActivityKind::MissionCreated.to_string(),
+            })
+            .await
+        {
+            warn!("Failed to record activity of mission {}: {}", mission_id, e);
+        }
+
+        Ok(true)
+    }
+
+    async fn get_active(&self, series_id: i32, chief_id: i32) -> Result<MissionSeriesEntity> {
+        let series = self.mission_series_repository.get_one(series_id).await?;
+        if series.chief_id != chief_id {
+            return Err(anyhow::anyhow!("Only the chief can manage this series"));
+        }
+        if series.cancelled_at.is_some() {
+            return Err(anyhow::anyhow!("Series has been cancelled"));
+        }
+        Ok(series)
+    }
+}
+
+fn check_rule(rule: &RecurrenceRule) -> Result<()> {
+    if rule.frequency == RecurrenceFrequency::Weekly && rule.weekdays.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Weekly series must run on at least one weekday"
+        ));
+    }
+    if rule.ends_at.is_some_and(|ends_at| ends_at < rule.starts_at) {
+        return Err(anyhow::anyhow!("Series must end after it starts"));
+    }
+    if rule
+        .max_occurrences
+        .is_some_and(|max_occurrences| max_occurrences < 1)
+    {
+        return Err(anyhow::anyhow!("Series must have at least one occurrence"));
+    }
+    Ok(())
+}
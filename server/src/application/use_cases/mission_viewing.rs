@@ -1,13 +1,38 @@
 use std::sync::Arc;
 
 use anyhow::{Ok, Result};
+use chrono::{NaiveDateTime, Utc};
 
 use crate::domain::{
     repositories::mission_viewing::MissionViewingRepository,
     value_objects::{
-        brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+        brawler_model::BrawlerModel,
+        mission_filter::{AutocompleteFilter, MissionFilter, RecommendationFilter},
+        mission_model::{MissionModel, MissionSuggestionModel},
+        mission_revision_model::{
+            FieldChangeModel, MissionRevisionDiffModel, MissionRevisionModel, RevisionDiffFilter,
+            RevisionNotFound,
+        },
+        mission_role_model::RoleSlotModel,
+        recommendation_model::{RecommendationSignalModel, RecommendedMissionModel},
     },
 };
+
+const DEFAULT_AUTOCOMPLETE_LIMIT: i64 = 5;
+const MAX_AUTOCOMPLETE_LIMIT: i64 = 20;
+const DEFAULT_RECOMMENDATION_LIMIT: i64 = 10;
+const MAX_RECOMMENDATION_LIMIT: i64 = 50;
+
+const TAG_WEIGHT: f64 = 0.4;
+const FRIEND_WEIGHT: f64 = 0.3;
+const REPUTATION_WEIGHT: f64 = 0.2;
+const RECENCY_WEIGHT: f64 = 0.1;
+/// This many followed brawlers in a mission already earn the full friend score.
+const FRIEND_SATURATION: f64 = 3.0;
+/// Unrated chiefs sit in the middle of the reputation scale.
+const NEUTRAL_REPUTATION: f64 = 0.5;
+const RECENCY_HALF_LIFE_HOURS: f64 = 72.0;
+
 pub struct MissionViewingUseCase<T>
 where
     T: MissionViewingRepository + Send + Sync,
@@ -33,7 +58,8 @@ where
 
         let model = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        let result = model.to_model(crew_count);
+        let mut result = model.to_model(crew_count);
+        result.progress = self.progress_of(mission_id).await;
 
         Ok(result)
     }
@@ -43,7 +69,35 @@ where
         Ok(result)
     }
 
+    pub async fn get_role_slots(&self, mission_id: i32) -> Result<Vec<RoleSlotModel>> {
+        let slots = self
+            .mission_viewing_repository
+            .get_role_slots(mission_id)
+            .await?;
+        let memberships = self
+            .mission_viewing_repository
+            .get_crew_memberships(mission_id)
+            .await?;
+
+        let result = slots
+            .iter()
+            .map(|slot| {
+                let filled = memberships
+                    .iter()
+                    .filter(|membership| membership.role.as_deref() == Some(slot.role.as_str()))
+                    .count() as i64;
+                slot.to_model(filled)
+            })
+            .collect();
+        Ok(result)
+    }
+
     pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
+        let q = filter.q.as_deref().map(str::trim).unwrap_or_default();
+        if !q.is_empty() {
+            return self.search(filter, q).await;
+        }
+
         let models = self.mission_viewing_repository.get_all(filter).await?;
 
         let mut result = Vec::new();
@@ -55,9 +109,267 @@ where
                 .await
                 .unwrap_or(0);
 
-            result.push(model.to_model(crew_count));
+            let mut mission = model.to_model(crew_count);
+            mission.progress = self.progress_of(model.id).await;
+            result.push(mission);
         }
 
         Ok(result)
     }
+
+    async fn search(&self, filter: &MissionFilter, q: &str) -> Result<Vec<MissionModel>> {
+        let models = self.mission_viewing_repository.search(filter, q).await?;
+
+        let mut result = Vec::new();
+
+        for (model, snippet) in models.into_iter() {
+            let crew_count = self
+                .mission_viewing_repository
+                .crew_counting(model.id)
+                .await
+                .unwrap_or(0);
+
+            let mut mission = model.to_model(crew_count);
+            mission.progress = self.progress_of(model.id).await;
+            mission.snippet = Some(snippet);
+            result.push(mission);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn autocomplete(
+        &self,
+        filter: &AutocompleteFilter,
+    ) -> Result<Vec<MissionSuggestionModel>> {
+        // Only letters and digits reach the tsquery, so user input can never
+        // inject operators into it.
+        let terms = filter
+            .q
+            .split_whitespace()
+            .map(|term| {
+                term.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .filter(|term| !term.is_empty())
+            .collect::<Vec<_>>();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT)
+            .clamp(1, MAX_AUTOCOMPLETE_LIMIT);
+
+        let suggestions = self
+            .mission_viewing_repository
+            .autocomplete(&terms, limit)
+            .await?;
+
+        let result = suggestions
+            .into_iter()
+            .map(|(id, name)| MissionSuggestionModel { id, name })
+            .collect();
+        Ok(result)
+    }
+
+    /// Ranks Open missions with a free place by how close they are to what the
+    /// brawler completed before, how many followed brawlers are in, the chief's
+    /// reputation and how new the mission is.
+    pub async fn get_recommended(
+        &self,
+        brawler_id: i32,
+        filter: &RecommendationFilter,
+    ) -> Result<Vec<RecommendedMissionModel>> {
+        let max_crew_per_mission: i64 = std::env::var("MAX_CREW_PER_MISSION")
+            .expect("missing value")
+            .parse()?;
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_RECOMMENDATION_LIMIT)
+            .clamp(1, MAX_RECOMMENDATION_LIMIT);
+
+        let signals = self
+            .mission_viewing_repository
+            .get_recommendation_signals(brawler_id, max_crew_per_mission)
+            .await?;
+
+        let now = Utc::now().naive_utc();
+        let mut ranked = signals
+            .iter()
+            .map(|signal| (signal, score_of(signal, now)))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .total_cmp(a_score)
+                .then(b.created_at.cmp(&a.created_at))
+        });
+        ranked.truncate(limit as usize);
+
+        let mut result = Vec::new();
+
+        for (signal, score) in ranked.into_iter() {
+            let crew_count = self
+                .mission_viewing_repository
+                .crew_counting(signal.mission_id)
+                .await
+                .unwrap_or(0);
+            let model = self
+                .mission_viewing_repository
+                .get_one(signal.mission_id)
+                .await?;
+
+            let mut mission = model.to_model(crew_count);
+            mission.progress = self.progress_of(signal.mission_id).await;
+            result.push(RecommendedMissionModel {
+                mission,
+                score,
+                reasons: reasons_of(signal, now),
+            });
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_followed(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+        let models = self
+            .mission_viewing_repository
+            .get_followed(brawler_id)
+            .await?;
+
+        let mut result = Vec::new();
+
+        for model in models.into_iter() {
+            let crew_count = self
+                .mission_viewing_repository
+                .crew_counting(model.id)
+                .await
+                .unwrap_or(0);
+
+            let mut mission = model.to_model(crew_count);
+            mission.progress = self.progress_of(model.id).await;
+            result.push(mission);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_revisions(&self, mission_id: i32) -> Result<Vec<MissionRevisionModel>> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let entities = self
+            .mission_viewing_repository
+            .get_revisions(mission_id)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    /// Compares two versions of a mission; defaults to the latest edit.
+    pub async fn get_revision_diff(
+        &self,
+        mission_id: i32,
+        filter: &RevisionDiffFilter,
+    ) -> Result<MissionRevisionDiffModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        let revisions = self
+            .mission_viewing_repository
+            .get_revisions(mission_id)
+            .await?;
+
+        // Versions start at 1; a mission that was never edited compares its
+        // first version with itself and has no changes.
+        let to_version = filter.to.unwrap_or(mission.version);
+        let from_version = filter.from.unwrap_or((to_version - 1).max(1));
+        if from_version > to_version {
+            return Err(anyhow::anyhow!("from must not be higher than to"));
+        }
+
+        let state_at = |version: i32| {
+            if version == mission.version {
+                return Some((mission.name.clone(), mission.description.clone()));
+            }
+            revisions
+                .iter()
+                .find(|revision| revision.version == version)
+                .map(|revision| (revision.name.clone(), revision.description.clone()))
+        };
+
+        let (from_name, from_description) = state_at(from_version).ok_or(RevisionNotFound {
+            version: from_version,
+        })?;
+        let (to_name, to_description) = state_at(to_version).ok_or(RevisionNotFound {
+            version: to_version,
+        })?;
+
+        let mut changes = Vec::new();
+        if from_name != to_name {
+            changes.push(FieldChangeModel {
+                field: "name".to_string(),
+                before: Some(from_name),
+                after: Some(to_name),
+            });
+        }
+        if from_description != to_description {
+            changes.push(FieldChangeModel {
+                field: "description".to_string(),
+                before: from_description,
+                after: to_description,
+            });
+        }
+
+        Ok(MissionRevisionDiffModel {
+            mission_id,
+            from_version,
+            to_version,
+            changes,
+        })
+    }
+
+    async fn progress_of(&self, mission_id: i32) -> Option<i32> {
+        self.mission_viewing_repository
+            .objective_progress(mission_id)
+            .await
+            .unwrap_or_default()
+    }
+}
+
+fn score_of(signal: &RecommendationSignalModel, now: NaiveDateTime) -> f64 {
+    let friend_score = (signal.followee_count as f64 / FRIEND_SATURATION).min(1.0);
+    let reputation_score = signal
+        .chief_reputation
+        .map_or(NEUTRAL_REPUTATION, |reputation| reputation / 5.0);
+    let age_hours = (now - signal.created_at).num_minutes().max(0) as f64 / 60.0;
+    let recency_score = 0.5_f64.powf(age_hours / RECENCY_HALF_LIFE_HOURS);
+
+    TAG_WEIGHT * signal.tag_affinity
+        + FRIEND_WEIGHT * friend_score
+        + REPUTATION_WEIGHT * reputation_score
+        + RECENCY_WEIGHT * recency_score
+}
+
+fn reasons_of(signal: &RecommendationSignalModel, now: NaiveDateTime) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if signal.tag_affinity > 0.0 {
+        reasons.push("Similar to missions you completed".to_string());
+    }
+    match signal.followee_count {
+        0 => {}
+        1 => reasons.push("A brawler you follow is in this mission".to_string()),
+        count => reasons.push(format!("{} brawlers you follow are in this mission", count)),
+    }
+    if signal
+        .chief_reputation
+        .is_some_and(|reputation| reputation >= 4.0)
+    {
+        reasons.push("Chief is highly rated".to_string());
+    }
+    if (now - signal.created_at).num_hours() < 24 {
+        reasons.push("Recently created".to_string());
+    }
+    reasons
 }
@@ -1,6 +1,18 @@
+pub mod achievement;
+pub mod activity;
+pub mod admin;
 pub mod authentication;
 pub mod brawlers;
 pub mod crew_operation;
+pub mod guild;
+pub mod leaderboard;
+pub mod matchmaking;
+pub mod mission_attachment;
 pub mod mission_management;
+pub mod mission_objective;
 pub mod mission_operation;
+pub mod mission_rating;
+pub mod mission_series;
 pub mod mission_viewing;
+pub mod progression;
+pub mod social;
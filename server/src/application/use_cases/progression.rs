@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::{
+    config::config_loader::get_progression_env,
+    domain::{
+        entities::points_ledger::AddPointsLedgerEntity,
+        repositories::points_ledger::PointsLedgerRepository,
+        value_objects::progression_model::{
+            PointsCorrectionModel, PointsHistoryFilter, PointsLedgerModel, PointsSource,
+            ProgressionModel,
+        },
+    },
+};
+
+const DEFAULT_HISTORY_LIMIT: i64 = 20;
+const MAX_HISTORY_LIMIT: i64 = 100;
+
+pub struct ProgressionUseCase<T>
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    points_ledger_repository: Arc<T>,
+}
+
+impl<T> ProgressionUseCase<T>
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    pub fn new(points_ledger_repository: Arc<T>) -> Self {
+        Self {
+            points_ledger_repository,
+        }
+    }
+
+    pub async fn get_progression(&self, brawler_id: i32) -> Result<ProgressionModel> {
+        let progression_env = get_progression_env()?;
+
+        let total_xp = self.points_ledger_repository.get_total(brawler_id).await?;
+
+        Ok(ProgressionModel::new(
+            brawler_id,
+            total_xp,
+            &progression_env.level_thresholds,
+        ))
+    }
+
+    pub async fn get_history(
+        &self,
+        brawler_id: i32,
+        filter: &PointsHistoryFilter,
+    ) -> Result<Vec<PointsLedgerModel>> {
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_HISTORY_LIMIT)
+            .clamp(1, MAX_HISTORY_LIMIT);
+
+        let entities = self
+            .points_ledger_repository
+            .get_history(brawler_id, filter.before, limit)
+            .await?;
+
+        let result = entities.iter().map(|entity| entity.to_model()).collect();
+        Ok(result)
+    }
+
+    /// Balances are never edited; a correction is just another ledger row.
+    pub async fn issue_correction(
+        &self,
+        issued_by: i32,
+        points_correction_model: PointsCorrectionModel,
+    ) -> Result<()> {
+        if points_correction_model.points == 0 {
+            return Err(anyhow::anyhow!("Correction must not be zero"));
+        }
+        if points_correction_model.note.trim().is_empty() {
+            return Err(anyhow::anyhow!("Correction must have a note"));
+        }
+
+        self.points_ledger_repository
+            .append(vec![AddPointsLedgerEntity {
+                brawler_id: points_correction_model.brawler_id,
+                source: PointsSource::Correction.to_string(),
+                source_id: None,
+                points: points_correction_model.points,
+                note: Some(points_correction_model.note.trim().to_string()),
+                issued_by: Some(issued_by),
+                source_at: None,
+            }])
+            .await?;
+
+        Ok(())
+    }
+}
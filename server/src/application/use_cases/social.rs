@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+
+use crate::domain::{
+    entities::{brawler_blocks::BrawlerBlockEntity, brawler_follows::BrawlerFollowEntity},
+    repositories::social::SocialRepository,
+    value_objects::social_model::SocialBrawlerModel,
+};
+
+pub struct SocialUseCase<T>
+where
+    T: SocialRepository + Send + Sync,
+{
+    social_repository: Arc<T>,
+}
+
+impl<T> SocialUseCase<T>
+where
+    T: SocialRepository + Send + Sync,
+{
+    pub fn new(social_repository: Arc<T>) -> Self {
+        Self { social_repository }
+    }
+
+    pub async fn follow(&self, follower_id: i32, followee_id: i32) -> Result<()> {
+        if follower_id == followee_id {
+            return Err(anyhow::anyhow!("You can not follow yourself!!"));
+        }
+        if self
+            .social_repository
+            .is_blocked_between(follower_id, followee_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("You can not follow this brawler"));
+        }
+
+        self.social_repository
+            .follow(BrawlerFollowEntity {
+                follower_id,
+                followee_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn unfollow(&self, follower_id: i32, followee_id: i32) -> Result<()> {
+        self.social_repository
+            .unfollow(BrawlerFollowEntity {
+                follower_id,
+                followee_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_following(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let result = self.social_repository.get_following(brawler_id).await?;
+        Ok(result)
+    }
+
+    pub async fn get_followers(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let result = self.social_repository.get_followers(brawler_id).await?;
+        Ok(result)
+    }
+
+    pub async fn get_friends(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let result = self.social_repository.get_friends(brawler_id).await?;
+        Ok(result)
+    }
+
+    pub async fn block(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        if blocker_id == blocked_id {
+            return Err(anyhow::anyhow!("You can not block yourself!!"));
+        }
+
+        self.social_repository
+            .block(BrawlerBlockEntity {
+                blocker_id,
+                blocked_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn unblock(&self, blocker_id: i32, blocked_id: i32) -> Result<()> {
+        self.social_repository
+            .unblock(BrawlerBlockEntity {
+                blocker_id,
+                blocked_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_blocked(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let result = self.social_repository.get_blocked(brawler_id).await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+use server::{
+    application::use_cases::{
+        admin::AdminUseCase, brawlers::BrawlersUseCase,
+        mission_management::MissionManagementUseCase,
+    },
+    config::{
+        config_loader::{
+            self, get_jwt_env, get_leaderboard_env, get_matchmaking_env, get_outcome_env,
+            get_progression_env, get_rating_env, get_scheduler_env, get_stage, get_trash_env,
+        },
+        stage::Stage,
+    },
+    domain::{
+        repositories::brawlers::BrawlerRepository,
+        value_objects::{brawler_model::RegisterBrawlerModel, mission_model::AddMissionModel},
+    },
+    infrastructure::{
+        database::{
+            migration_runner,
+            postgresql_connection::{self, PgPoolSquad},
+            repositories::{
+                achievement::AchievementPostgres, activity::ActivityPostgres,
+                brawlers::BrawlerPostgres, guild::GuildPostgres, leaderboard::LeaderboardPostgres,
+                mission_management::MissionManagementPostgres,
+                mission_template::MissionTemplatePostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        image_storage::ImageStorageBackend,
+    },
+};
+use tracing::{error, info, warn};
+
+/// Operational tasks, run against the database configured in `.env`.
+#[derive(Parser)]
+#[command(name = "admin")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Apply pending database migrations
+    Migrate {
+        /// Only report pending migrations
+        #[arg(long)]
+        check: bool,
+    },
+    /// Register a brawler with admin rights
+    CreateAdmin {
+        username: String,
+        #[arg(long)]
+        display_name: Option<String>,
+        #[arg(long, env = "ADMIN_CLI_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+    /// Set a new password for a brawler
+    ResetPassword {
+        username: String,
+        #[arg(long, env = "ADMIN_CLI_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+    /// Stop a brawler from logging in
+    Ban {
+        username: String,
+        /// Lift the ban instead
+        #[arg(long)]
+        lift: bool,
+    },
+    /// Delete trashed missions past the retention period
+    PurgeDeleted,
+    /// Register fake brawlers, each chief of one open mission
+    Seed {
+        #[arg(long)]
+        fake: usize,
+        #[arg(long, default_value = "password")]
+        password: String,
+    },
+    /// Refresh the leaderboard stats and award missed badges
+    RecountStats,
+    /// Load every config section and report the ones that fail
+    CheckConfig,
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let cli = Cli::parse();
+
+    if let Err(e) = run(cli.command).await {
+        error!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run(command: Command) -> Result<()> {
+    if let Command::CheckConfig = command {
+        return check_config();
+    }
+
+    let dotenvy_env = config_loader::load()?;
+    let db_pool = Arc::new(postgresql_connection::establish_connection(
+        &dotenvy_env.database.url,
+    )?);
+
+    if let Command::Migrate { check } = command {
+        let pending = migration_runner::migrate(&db_pool, !check)?;
+        if !pending.is_empty() {
+            return Err(anyhow::anyhow!(
+                "{} pending migrations: {}",
+                pending.len(),
+                pending.join(", ")
+            ));
+        }
+        return Ok(());
+    }
+    // Only warns about pending migrations, the command may still fail on them.
+    let pending = migration_runner::migrate(&db_pool, false)?;
+    if !pending.is_empty() {
+        warn!(
+            "{} pending migrations ({}), run `admin migrate` to apply them",
+            pending.len(),
+            pending.join(", ")
+        );
+    }
+
+    let admin_use_case = AdminUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LeaderboardPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    match command {
+        Command::CreateAdmin {
+            username,
+            display_name,
+            password,
+        } => {
+            let brawler_id = admin_use_case
+                .create_admin(RegisterBrawlerModel {
+                    username: username.clone(),
+                    password,
+                    display_name: display_name.unwrap_or(username.clone()),
+                })
+                .await?;
+            info!("Created admin {} (id {})", username, brawler_id);
+        }
+        Command::ResetPassword { username, password } => {
+            admin_use_case
+                .reset_password(username.clone(), password)
+                .await?;
+            info!("Reset the password of {}", username);
+        }
+        Command::Ban { username, lift } => {
+            admin_use_case.ban(username.clone(), !lift).await?;
+            if lift {
+                info!("Lifted the ban on {}", username);
+            } else {
+                info!("Banned {}", username);
+            }
+        }
+        Command::PurgeDeleted => {
+            let image_storage = Arc::new(ImageStorageBackend::from_env()?);
+            let purged = mission_management_use_case(&db_pool, image_storage)
+                .purge_deleted(Utc::now().naive_utc())
+                .await?;
+            info!("Purged {} deleted missions", purged);
+        }
+        Command::Seed { fake, password } => seed(&db_pool, fake, password).await?,
+        Command::RecountStats => {
+            let awarded = admin_use_case.recount_stats().await?;
+            info!("Leaderboard stats refreshed, {} badges awarded", awarded);
+        }
+        Command::Migrate { .. } | Command::CheckConfig => unreachable!(),
+    }
+
+    Ok(())
+}
+
+fn check_config() -> Result<()> {
+    let checks = [
+        ("server", config_loader::load().map(|_| ())),
+        ("jwt", get_jwt_env().map(|_| ())),
+        ("image storage", ImageStorageBackend::from_env().map(|_| ())),
+        ("rating", get_rating_env().map(|_| ())),
+        ("leaderboard", get_leaderboard_env().map(|_| ())),
+        ("progression", get_progression_env().map(|_| ())),
+        ("scheduler", get_scheduler_env().map(|_| ())),
+        ("trash", get_trash_env().map(|_| ())),
+        ("outcome", get_outcome_env().map(|_| ())),
+        ("matchmaking", get_matchmaking_env().map(|_| ())),
+    ];
+
+    let mut failed = 0;
+    for (section, result) in checks.iter() {
+        match result {
+            Ok(()) => info!("{}: ok", section),
+            Err(e) => {
+                warn!("{}: {}", section, e);
+                failed += 1;
+            }
+        }
+    }
+    info!("Stage: {}", get_stage());
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} config sections failed", failed));
+    }
+    Ok(())
+}
+
+async fn seed(db_pool: &Arc<PgPoolSquad>, count: usize, password: String) -> Result<()> {
+    if get_stage() == Stage::Production {
+        return Err(anyhow::anyhow!("Refusing to seed fake data in Prod"));
+    }
+
+    let image_storage = Arc::new(ImageStorageBackend::from_env()?);
+    let brawlers = brawlers_use_case(db_pool, Arc::clone(&image_storage));
+    let missions = mission_management_use_case(db_pool, image_storage);
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(db_pool));
+
+    for _ in 0..count {
+        let tag = uuid::Uuid::new_v4().simple().to_string()[..8].to_string();
+        let username = format!("fake_{}", tag);
+
+        brawlers
+            .register(RegisterBrawlerModel {
+                username: username.clone(),
+                password: password.clone(),
+                display_name: format!("Fake {}", tag),
+            })
+            .await?;
+        let chief_id = brawler_repository.find_by_username(username).await?.id;
+
+        missions
+            .add(
+                chief_id,
+                AddMissionModel {
+                    name: format!("Fake mission {}", tag),
+                    description: Some("Seeded by the admin CLI".to_string()),
+                    min_reputation: None,
+                    guild_id: None,
+                    guild_only: false,
+                    max_crew: None,
+                    tags: vec!["fake".to_string()],
+                    consensus_outcome: false,
+                    require_objectives: false,
+                },
+            )
+            .await?;
+    }
+
+    info!(
+        "Seeded {} fake brawlers with a mission each, password: {}",
+        count, password
+    );
+    Ok(())
+}
+
+fn brawlers_use_case(
+    db_pool: &Arc<PgPoolSquad>,
+    image_storage: Arc<ImageStorageBackend>,
+) -> BrawlersUseCase<BrawlerPostgres, AchievementPostgres, ImageStorageBackend> {
+    BrawlersUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(db_pool))),
+        image_storage,
+    )
+}
+
+fn mission_management_use_case(
+    db_pool: &Arc<PgPoolSquad>,
+    image_storage: Arc<ImageStorageBackend>,
+) -> MissionManagementUseCase<
+    MissionManagementPostgres,
+    MissionViewingPostgres,
+    GuildPostgres,
+    ActivityPostgres,
+    MissionTemplatePostgres,
+    ImageStorageBackend,
+> {
+    MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(db_pool))),
+        Arc::new(GuildPostgres::new(Arc::clone(db_pool))),
+        Arc::new(ActivityPostgres::new(Arc::clone(db_pool))),
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(db_pool))),
+        image_storage,
+    )
+}
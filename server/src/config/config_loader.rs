@@ -1,9 +1,13 @@
 use std::env;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
+    config_model::{
+        CloudinaryEnv, Database, DotEnvyConfig, ImageStorageEnv, JwtEnv, LeaderboardEnv,
+        LocalStorageEnv, MatchmakingEnv, OutcomeEnv, ProgressionEnv, RatingEnv, S3Env,
+        SchedulerEnv, Server, TrashEnv,
+    },
     stage::Stage,
 };
 
@@ -12,24 +16,27 @@ pub fn load() -> Result<DotEnvyConfig> {
 
     let server = Server {
         port: std::env::var("SERVER_PORT")
-            .expect("SERVER_PORT is valid")
+            .context("SERVER_PORT is missing")?
             .parse()?,
         body_limit: std::env::var("SERVER_BODY_LIMIT")
-            .expect("SERVER_BODY_LIMIT is valid")
+            .context("SERVER_BODY_LIMIT is missing")?
             .parse()?,
         timeout: std::env::var("SERVER_TIMEOUT")
-            .expect("SERVER_TIMEOUT is valid")
+            .context("SERVER_TIMEOUT is missing")?
             .parse()?,
     };
 
     let database = Database {
         url: std::env::var("DATABASE_URL")
-            .expect("DATABASE_URL is valid")
+            .context("DATABASE_URL is missing")?
+            .parse()?,
+        migrate_on_start: std::env::var("DATABASE_MIGRATE_ON_START")
+            .unwrap_or("false".to_string())
             .parse()?,
     };
 
     let secret = std::env::var("JWT_USER_SECRET")
-        .expect("SECRET is valid")
+        .context("JWT_USER_SECRET is missing")?
         .parse()?;
 
     let config = DotEnvyConfig {
@@ -62,5 +69,96 @@ pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
         cloud_name: env::var("CLOUDINARY_CLOUD_NAME")?,
         api_key: env::var("CLOUDINARY_API_KEY")?,
         api_secret: env::var("CLOUDINARY_API_SECRET")?,
+        base_url: env::var("CLOUDINARY_BASE_URL")
+            .unwrap_or("https://api.cloudinary.com/v1_1".to_string()),
+    })
+}
+
+pub fn get_image_storage_env() -> Result<ImageStorageEnv> {
+    dotenvy::dotenv().ok();
+    let backend = env::var("IMAGE_STORAGE_BACKEND").unwrap_or("cloudinary".to_string());
+    match backend.as_str() {
+        "cloudinary" => Ok(ImageStorageEnv::Cloudinary(get_cloudinary_env()?)),
+        "local" => Ok(ImageStorageEnv::Local(LocalStorageEnv {
+            dir: env::var("LOCAL_STORAGE_DIR")?,
+            public_url: env::var("LOCAL_STORAGE_PUBLIC_URL")?,
+        })),
+        "s3" => Ok(ImageStorageEnv::S3(S3Env {
+            endpoint: env::var("S3_ENDPOINT")?,
+            region: env::var("S3_REGION")?,
+            bucket: env::var("S3_BUCKET")?,
+            access_key: env::var("S3_ACCESS_KEY")?,
+            secret_key: env::var("S3_SECRET_KEY")?,
+            public_url: env::var("S3_PUBLIC_URL").ok().filter(|url| !url.is_empty()),
+        })),
+        _ => Err(anyhow::anyhow!(
+            "Invalid IMAGE_STORAGE_BACKEND: {}",
+            backend
+        )),
+    }
+}
+
+pub fn get_rating_env() -> Result<RatingEnv> {
+    dotenvy::dotenv().ok();
+    Ok(RatingEnv {
+        window_hours: env::var("RATING_WINDOW_HOURS")?.parse::<i64>()?,
+    })
+}
+
+pub fn get_leaderboard_env() -> Result<LeaderboardEnv> {
+    dotenvy::dotenv().ok();
+    Ok(LeaderboardEnv {
+        min_sample: env::var("LEADERBOARD_MIN_SAMPLE")?.parse::<i32>()?,
+    })
+}
+
+pub fn get_progression_env() -> Result<ProgressionEnv> {
+    dotenvy::dotenv().ok();
+
+    let mut level_thresholds = env::var("LEVEL_THRESHOLDS")?
+        .split(',')
+        .map(|threshold| threshold.trim().parse::<i64>())
+        .collect::<Result<Vec<i64>, _>>()?;
+    level_thresholds.sort_unstable();
+
+    Ok(ProgressionEnv {
+        chief_completed_xp: env::var("XP_CHIEF_COMPLETED")?.parse::<i32>()?,
+        crew_completed_xp: env::var("XP_CREW_COMPLETED")?.parse::<i32>()?,
+        chief_failed_xp: env::var("XP_CHIEF_FAILED")?.parse::<i32>()?,
+        crew_failed_xp: env::var("XP_CREW_FAILED")?.parse::<i32>()?,
+        level_thresholds,
+    })
+}
+
+pub fn get_scheduler_env() -> Result<SchedulerEnv> {
+    dotenvy::dotenv().ok();
+    Ok(SchedulerEnv {
+        interval_secs: env::var("SCHEDULER_INTERVAL_SECS")?.parse::<u64>()?,
+        series_lead_hours: env::var("SERIES_LEAD_HOURS")?.parse::<i64>()?,
+    })
+}
+
+pub fn get_trash_env() -> Result<TrashEnv> {
+    dotenvy::dotenv().ok();
+    Ok(TrashEnv {
+        retention_days: env::var("TRASH_RETENTION_DAYS")?.parse::<i64>()?,
+        purge_interval_secs: env::var("TRASH_PURGE_INTERVAL_SECS")?.parse::<u64>()?,
+    })
+}
+
+pub fn get_outcome_env() -> Result<OutcomeEnv> {
+    dotenvy::dotenv().ok();
+    Ok(OutcomeEnv {
+        quorum_percent: env::var("OUTCOME_QUORUM_PERCENT")?.parse::<i64>()?,
+        dispute_hours: env::var("OUTCOME_DISPUTE_HOURS")?.parse::<i64>()?,
+    })
+}
+
+pub fn get_matchmaking_env() -> Result<MatchmakingEnv> {
+    dotenvy::dotenv().ok();
+    Ok(MatchmakingEnv {
+        interval_secs: env::var("MATCHMAKING_INTERVAL_SECS")?.parse::<u64>()?,
+        proposal_timeout_secs: env::var("MATCHMAKING_PROPOSAL_TIMEOUT_SECS")?.parse::<i64>()?,
+        crew_size: env::var("MATCHMAKING_CREW_SIZE")?.parse::<usize>()?,
     })
 }
@@ -8,6 +8,7 @@ pub struct Server {
 #[derive(Debug, Clone)]
 pub struct Database {
     pub url: String,
+    pub migrate_on_start: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +22,74 @@ pub struct CloudinaryEnv {
     pub cloud_name: String,
     pub api_key: String,
     pub api_secret: String,
+    pub base_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalStorageEnv {
+    pub dir: String,
+    pub public_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct S3Env {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key: String,
+    pub secret_key: String,
+    pub public_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ImageStorageEnv {
+    Cloudinary(CloudinaryEnv),
+    Local(LocalStorageEnv),
+    S3(S3Env),
+}
+
+#[derive(Debug, Clone)]
+pub struct RatingEnv {
+    pub window_hours: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardEnv {
+    pub min_sample: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProgressionEnv {
+    pub chief_completed_xp: i32,
+    pub crew_completed_xp: i32,
+    pub chief_failed_xp: i32,
+    pub crew_failed_xp: i32,
+    pub level_thresholds: Vec<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchedulerEnv {
+    pub interval_secs: u64,
+    pub series_lead_hours: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrashEnv {
+    pub retention_days: i64,
+    pub purge_interval_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutcomeEnv {
+    pub quorum_percent: i64,
+    pub dispute_hours: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchmakingEnv {
+    pub interval_secs: u64,
+    pub proposal_timeout_secs: i64,
+    pub crew_size: usize,
 }
 
 #[derive(Debug, Clone)]
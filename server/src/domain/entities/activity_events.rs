@@ -0,0 +1,11 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::activity_events;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = activity_events)]
+pub struct AddActivityEventEntity {
+    pub actor_id: i32,
+    pub mission_id: i32,
+    pub kind: String,
+}
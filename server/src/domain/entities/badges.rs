@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::badge_model::BadgeModel, infrastructure::database::schema::badges,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = badges)]
+pub struct BadgeEntity {
+    pub id: i32,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub metric: String,
+    pub threshold: i32,
+    pub window_days: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+impl BadgeEntity {
+    pub fn to_model(&self) -> BadgeModel {
+        BadgeModel {
+            code: self.code.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            icon_url: self.icon_url.clone(),
+            metric: self.metric.clone(),
+            threshold: self.threshold,
+            window_days: self.window_days,
+        }
+    }
+}
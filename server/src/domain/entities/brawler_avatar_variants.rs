@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{entities::brawlers::BrawlerEntity, value_objects::brawler_model::AvatarVariantModel},
+    infrastructure::database::schema::brawler_avatar_variants,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key=brawler_id))]
+#[diesel(table_name = brawler_avatar_variants)]
+pub struct BrawlerAvatarVariantEntity {
+    pub brawler_id: i32,
+    pub size: i32,
+    pub url: String,
+    pub public_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl BrawlerAvatarVariantEntity {
+    pub fn to_model(&self) -> AvatarVariantModel {
+        AvatarVariantModel {
+            size: self.size,
+            url: self.url.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_avatar_variants)]
+pub struct AddBrawlerAvatarVariantEntity {
+    pub brawler_id: i32,
+    pub size: i32,
+    pub url: String,
+    pub public_id: String,
+}
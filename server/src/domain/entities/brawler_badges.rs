@@ -0,0 +1,32 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{badges::BadgeEntity, brawlers::BrawlerEntity},
+        value_objects::badge_model::AwardedBadgeModel,
+    },
+    infrastructure::database::schema::brawler_badges,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, QueryableByName, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key=brawler_id))]
+#[diesel(belongs_to(BadgeEntity, foreign_key=badge_id))]
+#[diesel(table_name = brawler_badges)]
+pub struct BrawlerBadgeEntity {
+    pub brawler_id: i32,
+    pub badge_id: i32,
+    pub awarded_at: NaiveDateTime,
+}
+
+impl BrawlerBadgeEntity {
+    pub fn to_model(&self, badge: &BadgeEntity) -> AwardedBadgeModel {
+        AwardedBadgeModel {
+            code: badge.code.clone(),
+            name: badge.name.clone(),
+            description: badge.description.clone(),
+            icon_url: badge.icon_url.clone(),
+            awarded_at: self.awarded_at,
+        }
+    }
+}
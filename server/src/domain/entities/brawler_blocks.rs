@@ -0,0 +1,10 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::brawler_blocks;
+
+#[derive(Debug, Clone, Selectable, Queryable, Insertable)]
+#[diesel(table_name = brawler_blocks)]
+pub struct BrawlerBlockEntity {
+    pub blocker_id: i32,
+    pub blocked_id: i32,
+}
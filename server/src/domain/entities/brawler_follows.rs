@@ -0,0 +1,10 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::brawler_follows;
+
+#[derive(Debug, Clone, Selectable, Queryable, Insertable)]
+#[diesel(table_name = brawler_follows)]
+pub struct BrawlerFollowEntity {
+    pub follower_id: i32,
+    pub followee_id: i32,
+}
@@ -13,6 +13,8 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
+    pub is_admin: bool,
+    pub banned_at: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -13,4 +13,5 @@ use crate::{
 pub struct CrewMemberShips {
     pub brawler_id: i32,
     pub mission_id: i32,
+    pub role: Option<String>,
 }
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{entities::guilds::GuildEntity, value_objects::guild_model::GuildInvitationModel},
+    infrastructure::database::schema::guild_invitations,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(GuildEntity, foreign_key=guild_id))]
+#[diesel(table_name = guild_invitations)]
+pub struct GuildInvitationEntity {
+    pub guild_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl GuildInvitationEntity {
+    pub fn to_model(&self, guild: &GuildEntity) -> GuildInvitationModel {
+        GuildInvitationModel {
+            guild_id: self.guild_id,
+            guild_name: guild.name.clone(),
+            invited_by: self.invited_by,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = guild_invitations)]
+pub struct AddGuildInvitationEntity {
+    pub guild_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+}
@@ -0,0 +1,16 @@
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::{brawlers::BrawlerEntity, guilds::GuildEntity},
+    infrastructure::database::schema::guild_memberships,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Insertable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key=brawler_id))]
+#[diesel(belongs_to(GuildEntity, foreign_key=guild_id))]
+#[diesel(table_name = guild_memberships)]
+pub struct GuildMembershipEntity {
+    pub guild_id: i32,
+    pub brawler_id: i32,
+    pub role: String,
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::guild_model::GuildModel, infrastructure::database::schema::guilds,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = guilds)]
+pub struct GuildEntity {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl GuildEntity {
+    pub fn to_model(&self, member_count: i64) -> GuildModel {
+        GuildModel {
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            owner_id: self.owner_id,
+            member_count,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = guilds)]
+pub struct AddGuildEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_id: i32,
+}
@@ -0,0 +1,54 @@
+use chrono::{Duration, NaiveDateTime};
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::brawlers::BrawlerEntity, value_objects::matchmaking_model::MatchmakingTicketModel,
+    },
+    infrastructure::database::schema::matchmaking_tickets,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key=brawler_id))]
+#[diesel(primary_key(brawler_id))]
+#[diesel(table_name = matchmaking_tickets)]
+pub struct MatchmakingTicketEntity {
+    pub brawler_id: i32,
+    pub category: Option<String>,
+    pub role: Option<String>,
+    pub min_reputation: Option<f64>,
+    pub status: String,
+    pub mission_id: Option<i32>,
+    pub proposed_at: Option<NaiveDateTime>,
+    pub declined_mission_ids: Vec<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MatchmakingTicketEntity {
+    pub fn to_model(&self, proposal_timeout: Duration) -> MatchmakingTicketModel {
+        MatchmakingTicketModel {
+            brawler_id: self.brawler_id,
+            category: self.category.clone(),
+            role: self.role.clone(),
+            min_reputation: self.min_reputation,
+            status: self.status.clone(),
+            mission_id: self.mission_id,
+            proposed_at: self.proposed_at,
+            expires_at: self
+                .proposed_at
+                .map(|proposed_at| proposed_at + proposal_timeout),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = matchmaking_tickets)]
+pub struct AddMatchmakingTicketEntity {
+    pub brawler_id: i32,
+    pub category: Option<String>,
+    pub role: Option<String>,
+    pub min_reputation: Option<f64>,
+    pub status: String,
+}
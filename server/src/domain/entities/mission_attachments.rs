@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::missions::MissionEntity,
+        value_objects::mission_attachment_model::MissionAttachmentModel,
+    },
+    infrastructure::database::schema::mission_attachments,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_attachments)]
+pub struct MissionAttachmentEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub file_name: Option<String>,
+    pub mime_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub public_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionAttachmentEntity {
+    pub fn to_model(&self) -> MissionAttachmentModel {
+        MissionAttachmentModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            uploaded_by: self.uploaded_by,
+            file_name: self.file_name.clone(),
+            mime_type: self.mime_type.clone(),
+            size_bytes: self.size_bytes,
+            url: self.url.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_attachments)]
+pub struct AddMissionAttachmentEntity {
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub file_name: Option<String>,
+    pub mime_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub public_id: String,
+}
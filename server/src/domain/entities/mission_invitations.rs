@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::missions::MissionEntity, value_objects::mission_model::MissionInvitationModel,
+    },
+    infrastructure::database::schema::mission_invitations,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_invitations)]
+pub struct MissionInvitationEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionInvitationEntity {
+    pub fn to_model(&self, mission: &MissionEntity) -> MissionInvitationModel {
+        MissionInvitationModel {
+            mission_id: self.mission_id,
+            mission_name: mission.name.clone(),
+            invited_by: self.invited_by,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_invitations)]
+pub struct AddMissionInvitationEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+}
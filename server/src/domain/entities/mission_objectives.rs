@@ -0,0 +1,55 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::missions::MissionEntity,
+        value_objects::mission_objective_model::MissionObjectiveModel,
+    },
+    infrastructure::database::schema::mission_objectives,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_objectives)]
+pub struct MissionObjectiveEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionObjectiveEntity {
+    pub fn to_model(&self) -> MissionObjectiveModel {
+        MissionObjectiveModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            position: self.position,
+            title: self.title.clone(),
+            required: self.required,
+            completed_by: self.completed_by,
+            completed_at: self.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_objectives)]
+pub struct AddMissionObjectiveEntity {
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_objectives)]
+pub struct EditMissionObjectiveEntity {
+    pub title: Option<String>,
+    pub required: Option<bool>,
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::missions::MissionEntity,
+    infrastructure::database::schema::mission_outcome_votes,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_outcome_votes)]
+pub struct MissionOutcomeVoteEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub outcome: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_outcome_votes)]
+pub struct AddMissionOutcomeVoteEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub outcome: String,
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_rating_model::MissionRatingModel,
+    infrastructure::database::schema::mission_ratings,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_ratings)]
+pub struct MissionRatingEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionRatingEntity {
+    pub fn to_model(&self) -> MissionRatingModel {
+        MissionRatingModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            rater_id: self.rater_id,
+            ratee_id: self.ratee_id,
+            score: self.score,
+            comment: self.comment.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_ratings)]
+pub struct AddMissionRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+}
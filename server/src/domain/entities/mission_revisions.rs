@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::missions::MissionEntity,
+        value_objects::mission_revision_model::MissionRevisionModel,
+    },
+    infrastructure::database::schema::mission_revisions,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_revisions)]
+pub struct MissionRevisionEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub version: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub edited_by: i32,
+    pub edited_at: NaiveDateTime,
+}
+
+impl MissionRevisionEntity {
+    pub fn to_model(&self) -> MissionRevisionModel {
+        MissionRevisionModel {
+            version: self.version,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            edited_by: self.edited_by,
+            edited_at: self.edited_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_revisions)]
+pub struct AddMissionRevisionEntity {
+    pub mission_id: i32,
+    pub version: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub edited_by: i32,
+}
@@ -0,0 +1,25 @@
+use diesel::prelude::*;
+
+use crate::{
+    domain::{entities::missions::MissionEntity, value_objects::mission_role_model::RoleSlotModel},
+    infrastructure::database::schema::mission_role_slots,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable, Insertable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key=mission_id))]
+#[diesel(table_name = mission_role_slots)]
+pub struct MissionRoleSlotEntity {
+    pub mission_id: i32,
+    pub role: String,
+    pub capacity: i32,
+}
+
+impl MissionRoleSlotEntity {
+    pub fn to_model(&self, filled: i64) -> RoleSlotModel {
+        RoleSlotModel {
+            role: self.role.clone(),
+            capacity: self.capacity,
+            filled,
+        }
+    }
+}
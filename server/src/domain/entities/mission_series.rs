@@ -0,0 +1,118 @@
+use chrono::{NaiveDateTime, Weekday};
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_series_model::{
+        MissionSeriesModel, RecurrenceFrequency, RecurrenceRule,
+    },
+    infrastructure::database::schema::mission_series,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_series)]
+pub struct MissionSeriesEntity {
+    pub id: i32,
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub frequency: String,
+    pub weekdays: Vec<i32>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+    pub occurrence_count: i32,
+    pub next_occurrence_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionSeriesEntity {
+    pub fn to_model(&self) -> MissionSeriesModel {
+        MissionSeriesModel {
+            id: self.id,
+            chief_id: self.chief_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+            min_reputation: self.min_reputation,
+            guild_id: self.guild_id,
+            guild_only: self.guild_only,
+            frequency: self.frequency.clone(),
+            weekdays: weekdays_from_db(&self.weekdays),
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            max_occurrences: self.max_occurrences,
+            occurrence_count: self.occurrence_count,
+            next_occurrence_at: self.next_occurrence_at,
+            cancelled_at: self.cancelled_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn to_rule(&self) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: RecurrenceFrequency::try_from(&self.frequency).unwrap_or_default(),
+            weekdays: weekdays_from_db(&self.weekdays),
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            max_occurrences: self.max_occurrences,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_series)]
+pub struct AddMissionSeriesEntity {
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub frequency: String,
+    pub weekdays: Vec<i32>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+    pub next_occurrence_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_series)]
+pub struct EditMissionSeriesEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub weekdays: Option<Vec<i32>>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+    pub next_occurrence_at: Option<Option<NaiveDateTime>>,
+}
+
+/// Weekdays are stored as days from Monday (0 = Monday, 6 = Sunday).
+pub fn weekdays_to_db(weekdays: &[Weekday]) -> Vec<i32> {
+    weekdays
+        .iter()
+        .map(|weekday| weekday.num_days_from_monday() as i32)
+        .collect()
+}
+
+fn weekdays_from_db(weekdays: &[i32]) -> Vec<Weekday> {
+    weekdays
+        .iter()
+        .filter_map(|weekday| u8::try_from(*weekday).ok())
+        .filter_map(|weekday| Weekday::try_from(weekday).ok())
+        .collect()
+}
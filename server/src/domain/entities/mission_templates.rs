@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_template_model::MissionTemplateModel,
+    infrastructure::database::schema::mission_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_templates)]
+pub struct MissionTemplateEntity {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionTemplateEntity {
+    pub fn to_model(&self) -> MissionTemplateModel {
+        MissionTemplateModel {
+            id: self.id,
+            owner_id: self.owner_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_templates)]
+pub struct AddMissionTemplateEntity {
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+}
@@ -1,8 +1,9 @@
-use chrono::NaiveDateTime;
+use chrono::{Duration, NaiveDateTime};
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
+    domain::value_objects::mission_model::{DeletedMissionModel, MissionModel},
+    infrastructure::database::schema::missions,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -17,6 +18,20 @@ pub struct MissionEntity {
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub finished_at: Option<NaiveDateTime>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub series_id: Option<i32>,
+    pub scheduled_for: Option<NaiveDateTime>,
+    pub version: i32,
+    pub consensus_outcome: bool,
+    pub outcome_overridden_at: Option<NaiveDateTime>,
+    pub require_objectives: bool,
+    pub banner_url: Option<String>,
+    pub banner_public_id: Option<String>,
 }
 
 impl MissionEntity {
@@ -28,10 +43,48 @@ impl MissionEntity {
             status: self.status.clone(),
             chief_id: self.chief_id,
             crew_count,
+            min_reputation: self.min_reputation,
+            guild_id: self.guild_id,
+            guild_only: self.guild_only,
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+            series_id: self.series_id,
+            scheduled_for: self.scheduled_for,
+            version: self.version,
+            consensus_outcome: self.consensus_outcome,
+            outcome_overridden_at: self.outcome_overridden_at,
+            require_objectives: self.require_objectives,
+            progress: None,
+            banner_url: self.banner_url.clone(),
             created_at: self.created_at,
             updated_at: self.updated_at,
+            finished_at: self.finished_at,
+            snippet: None,
         }
     }
+
+    /// Whether the crew can still overturn the outcome of this finished mission.
+    pub fn is_disputable(&self, dispute_hours: i64, now: NaiveDateTime) -> bool {
+        self.outcome_overridden_at.is_none()
+            && self
+                .finished_at
+                .is_some_and(|finished_at| now <= finished_at + Duration::hours(dispute_hours))
+    }
+
+    /// Only meaningful for soft-deleted missions; `None` otherwise.
+    pub fn to_deleted_model(&self, retention: Duration) -> Option<DeletedMissionModel> {
+        let deleted_at = self.deleted_at?;
+        Some(DeletedMissionModel {
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            status: self.status.clone(),
+            tags: self.tags.clone(),
+            created_at: self.created_at,
+            deleted_at,
+            purge_at: deleted_at + retention,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -41,12 +94,25 @@ pub struct AddMissionEntity {
     pub name: String,
     pub status: String,
     pub description: Option<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub series_id: Option<i32>,
+    pub scheduled_for: Option<NaiveDateTime>,
+    pub consensus_outcome: bool,
+    pub require_objectives: bool,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
 #[diesel(table_name = missions)]
 pub struct EditMissionEntity {
-    pub chief_id: i32,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_reputation: Option<Option<f64>>,
+    pub max_crew: Option<Option<i32>>,
+    pub tags: Option<Vec<String>>,
+    pub consensus_outcome: Option<bool>,
+    pub require_objectives: Option<bool>,
 }
@@ -1,3 +1,24 @@
+pub mod activity_events;
+pub mod badges;
+pub mod brawler_avatar_variants;
+pub mod brawler_badges;
+pub mod brawler_blocks;
+pub mod brawler_follows;
 pub mod brawlers;
 pub mod crew_memberships;
+pub mod guild_invitations;
+pub mod guild_memberships;
+pub mod guilds;
+pub mod matchmaking_tickets;
+pub mod mission_attachments;
+pub mod mission_invitations;
+pub mod mission_objectives;
+pub mod mission_outcome_votes;
+pub mod mission_ratings;
+pub mod mission_revisions;
+pub mod mission_role_slots;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod missions;
+pub mod points_ledger;
+pub mod seasons;
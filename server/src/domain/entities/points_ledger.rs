@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::progression_model::PointsLedgerModel,
+    infrastructure::database::schema::points_ledger,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = points_ledger)]
+pub struct PointsLedgerEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub source: String,
+    pub source_id: Option<i32>,
+    pub points: i32,
+    pub note: Option<String>,
+    pub issued_by: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub source_at: Option<NaiveDateTime>,
+}
+
+impl PointsLedgerEntity {
+    pub fn to_model(&self) -> PointsLedgerModel {
+        PointsLedgerModel {
+            id: self.id,
+            source: self.source.clone(),
+            source_id: self.source_id,
+            points: self.points,
+            note: self.note.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = points_ledger)]
+pub struct AddPointsLedgerEntity {
+    pub brawler_id: i32,
+    pub source: String,
+    pub source_id: Option<i32>,
+    pub points: i32,
+    pub note: Option<String>,
+    pub issued_by: Option<i32>,
+    /// When the mission run behind a mission row finished; `None` for corrections.
+    pub source_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{badges::BadgeEntity, brawler_badges::BrawlerBadgeEntity};
+
+#[async_trait]
+pub trait AchievementRepository {
+    async fn evaluate(&self, brawler_ids: Vec<i32>) -> Result<Vec<BrawlerBadgeEntity>>;
+    async fn get_definitions(&self) -> Result<Vec<BadgeEntity>>;
+    async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<(BrawlerBadgeEntity, BadgeEntity)>>;
+}
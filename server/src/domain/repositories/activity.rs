@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::activity_events::AddActivityEventEntity,
+    value_objects::activity_model::ActivityEventModel,
+};
+
+#[async_trait]
+pub trait ActivityRepository {
+    async fn record(&self, add_activity_event_entity: AddActivityEventEntity) -> Result<()>;
+    /// `viewer_id` narrows the feed to people the viewer follows and missions
+    /// the viewer leads or crews; `None` returns the global feed.
+    async fn get_feed(
+        &self,
+        viewer_id: Option<i32>,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<ActivityEventModel>>;
+}
@@ -1,21 +1,37 @@
 use crate::{
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
-        value_objects::{base64_img::Base64Img, uploaded_img::UploadedImg},
+        entities::{
+            brawler_avatar_variants::{AddBrawlerAvatarVariantEntity, BrawlerAvatarVariantEntity},
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        },
+        value_objects::brawler_model::BrawlerProfileModel,
     },
-    infrastructure::{cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::jwt::jwt_model::Passport,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait BrawlerRepository {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport>;
     async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
-    async fn upload_base64img(
+    async fn find_by_id(&self, brawler_id: i32) -> Result<Option<BrawlerEntity>>;
+    async fn get_profile(&self, brawler_id: i32) -> Result<BrawlerProfileModel>;
+    /// Replaces the avatar thumbnails, the largest one becoming the avatar itself.
+    /// Returns the public ids of every file the old avatar used.
+    async fn update_avatar(
         &self,
         user_id: i32,
-        base64img: Base64Img,
-        opt: UploadImageOptions,
-    ) -> Result<UploadedImg>;
+        variants: Vec<AddBrawlerAvatarVariantEntity>,
+    ) -> Result<Vec<String>>;
+    /// Clears the avatar, returning the public ids of every file it used.
+    async fn remove_avatar(&self, user_id: i32) -> Result<Vec<String>>;
+    async fn get_avatar_variants(&self, brawler_id: i32)
+    -> Result<Vec<BrawlerAvatarVariantEntity>>;
+    async fn get_ids(&self) -> Result<Vec<i32>>;
+    async fn update_password(&self, brawler_id: i32, password: String) -> Result<()>;
+    /// Inserts the brawler with admin rights in a single statement.
+    async fn register_admin(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
+    async fn set_banned_at(&self, brawler_id: i32, banned_at: Option<NaiveDateTime>) -> Result<()>;
 }
@@ -1,10 +1,19 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::domain::entities::crew_memberships::CrewMemberShips;
+use crate::domain::entities::{
+    crew_memberships::CrewMemberShips, mission_invitations::MissionInvitationEntity,
+    missions::MissionEntity,
+};
 
 #[async_trait]
 pub trait CrewOperationRepository {
     async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
     async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    async fn change_role(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    async fn get_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> Result<Vec<(MissionInvitationEntity, MissionEntity)>>;
+    async fn remove_invitation(&self, mission_id: i32, brawler_id: i32) -> Result<usize>;
 }
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        guild_invitations::{AddGuildInvitationEntity, GuildInvitationEntity},
+        guilds::{AddGuildEntity, GuildEntity},
+    },
+    value_objects::guild_model::{GuildMemberModel, GuildStatsModel},
+};
+
+#[async_trait]
+pub trait GuildRepository {
+    async fn create(&self, add_guild_entity: AddGuildEntity) -> Result<i32>;
+    async fn get_one(&self, guild_id: i32) -> Result<GuildEntity>;
+    async fn get_members(&self, guild_id: i32) -> Result<Vec<GuildMemberModel>>;
+    async fn get_role(&self, guild_id: i32, brawler_id: i32) -> Result<Option<String>>;
+    async fn get_stats(&self, guild_id: i32) -> Result<GuildStatsModel>;
+    async fn invite(&self, add_guild_invitation_entity: AddGuildInvitationEntity) -> Result<()>;
+    async fn get_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> Result<Vec<(GuildInvitationEntity, GuildEntity)>>;
+    async fn accept_invitation(&self, guild_id: i32, brawler_id: i32) -> Result<()>;
+    async fn decline_invitation(&self, guild_id: i32, brawler_id: i32) -> Result<()>;
+    async fn update_role(&self, guild_id: i32, brawler_id: i32, role: String) -> Result<()>;
+    async fn remove_member(&self, guild_id: i32, brawler_id: i32) -> Result<()>;
+}
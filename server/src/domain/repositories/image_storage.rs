@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::{
+    upload_file::UploadFile,
+    uploaded_img::{UploadImageOptions, UploadedImg},
+};
+
+#[async_trait]
+pub trait ImageStorage {
+    async fn upload(&self, file: UploadFile, opt: UploadImageOptions) -> Result<UploadedImg>;
+    /// Deleting a file that is already gone is not an error.
+    async fn delete(&self, public_id: &str) -> Result<()>;
+    fn public_url(&self, public_id: &str) -> String;
+}
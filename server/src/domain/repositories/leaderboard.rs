@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::seasons::SeasonEntity,
+    value_objects::leaderboard_model::{LeaderboardEntryModel, LeaderboardMetric},
+};
+
+#[async_trait]
+pub trait LeaderboardRepository {
+    async fn get_leaderboard(
+        &self,
+        metric: LeaderboardMetric,
+        season_id: i32,
+        min_sample: i32,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntryModel>>;
+    async fn get_seasons(&self) -> Result<Vec<SeasonEntity>>;
+    async fn refresh(&self) -> Result<()>;
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{
+    matchmaking_tickets::{AddMatchmakingTicketEntity, MatchmakingTicketEntity},
+    missions::MissionEntity,
+};
+
+#[async_trait]
+pub trait MatchmakingRepository {
+    async fn enqueue(
+        &self,
+        add_matchmaking_ticket_entity: AddMatchmakingTicketEntity,
+    ) -> Result<()>;
+    async fn get_ticket(&self, brawler_id: i32) -> Result<Option<MatchmakingTicketEntity>>;
+    async fn remove(&self, brawler_id: i32) -> Result<usize>;
+    async fn get_by_status(&self, status: &str) -> Result<Vec<MatchmakingTicketEntity>>;
+    /// Open, public missions in the order they were created.
+    async fn get_open_missions(&self) -> Result<Vec<MissionEntity>>;
+    /// Returns `false` when the ticket left the queue in the meantime.
+    async fn propose(&self, brawler_id: i32, mission_id: i32) -> Result<bool>;
+    /// Puts a proposed ticket back in the queue; the declined mission is never
+    /// proposed to it again.
+    async fn requeue(&self, brawler_id: i32) -> Result<()>;
+}
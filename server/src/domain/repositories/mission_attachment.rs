@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_attachments::{AddMissionAttachmentEntity, MissionAttachmentEntity},
+    value_objects::uploaded_img::UploadedImg,
+};
+
+#[async_trait]
+pub trait MissionAttachmentRepository {
+    /// Points the banner at an uploaded file, returning the public id it replaced.
+    async fn set_banner(
+        &self,
+        mission_id: i32,
+        uploaded_img: UploadedImg,
+    ) -> Result<Option<String>>;
+    /// Clears the banner, returning the public id of the removed file.
+    async fn remove_banner(&self, mission_id: i32) -> Result<String>;
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionAttachmentEntity>>;
+    async fn get_one(&self, attachment_id: i32) -> Result<MissionAttachmentEntity>;
+    async fn count(&self, mission_id: i32) -> Result<i64>;
+    async fn add(&self, add_mission_attachment_entity: AddMissionAttachmentEntity) -> Result<i32>;
+    /// Deletes the attachment, returning the public id of its file.
+    async fn remove(&self, attachment_id: i32) -> Result<String>;
+}
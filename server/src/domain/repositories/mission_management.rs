@@ -1,11 +1,37 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
-use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
+use crate::domain::entities::{
+    mission_invitations::AddMissionInvitationEntity,
+    mission_role_slots::MissionRoleSlotEntity,
+    missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+};
 
 #[async_trait]
 pub trait MissionManagementRepository {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
+    /// Records the replaced name and description as a revision and returns the new version.
+    /// Fails with `VersionConflict` when `expected_version` no longer matches.
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        expected_version: Option<i32>,
+        edit_mission_entity: EditMissionEntity,
+    ) -> Result<i32>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    /// Deleted missions of a chief that are still restorable, newest first.
+    async fn get_deleted(&self, chief_id: i32, since: NaiveDateTime) -> Result<Vec<MissionEntity>>;
+    async fn restore(&self, mission_id: i32, chief_id: i32, since: NaiveDateTime) -> Result<i32>;
+    /// Hard-deletes missions deleted before `before` together with their dependent rows.
+    /// Returns how many were purged and the public ids of the files they left behind.
+    async fn purge_deleted(&self, before: NaiveDateTime) -> Result<(usize, Vec<String>)>;
+    /// Replaces every role slot of the mission.
+    async fn set_role_slots(
+        &self,
+        mission_id: i32,
+        slots: Vec<MissionRoleSlotEntity>,
+    ) -> Result<()>;
+    async fn invite_crew(&self, invitations: Vec<AddMissionInvitationEntity>) -> Result<()>;
 }
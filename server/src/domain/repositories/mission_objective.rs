@@ -0,0 +1,24 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::mission_objectives::{
+    AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+};
+
+#[async_trait]
+pub trait MissionObjectiveRepository {
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionObjectiveEntity>>;
+    async fn get_one(&self, objective_id: i32) -> Result<MissionObjectiveEntity>;
+    /// Appends the objective after the last one of its mission.
+    async fn add(&self, add_mission_objective_entity: AddMissionObjectiveEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_mission_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, objective_id: i32) -> Result<()>;
+    /// Rewrites positions to follow the order of `objective_ids`.
+    async fn reorder(&self, mission_id: i32, objective_ids: Vec<i32>) -> Result<()>;
+    /// `None` clears the completion.
+    async fn set_completed(&self, objective_id: i32, completed_by: Option<i32>) -> Result<()>;
+}
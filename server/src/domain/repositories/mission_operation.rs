@@ -1,9 +1,26 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
+use crate::domain::{
+    entities::mission_outcome_votes::{AddMissionOutcomeVoteEntity, MissionOutcomeVoteEntity},
+    value_objects::mission_statuses::MissionStatuses,
+};
+
 #[async_trait]
 pub trait MissionOperationRepository {
+    /// Status changes only apply to the status they were checked against and
+    /// fail with `StatusConflict` otherwise.
+    /// Restarting a Failed mission also clears its outcome votes and override.
     async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
     async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
     async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    /// Replaces a finished mission's outcome once; `finished_at` is kept as is.
+    async fn override_outcome(
+        &self,
+        mission_id: i32,
+        from: MissionStatuses,
+        to: MissionStatuses,
+    ) -> Result<i32>;
+    async fn vote_outcome(&self, add_vote_entity: AddMissionOutcomeVoteEntity) -> Result<()>;
+    async fn get_outcome_votes(&self, mission_id: i32) -> Result<Vec<MissionOutcomeVoteEntity>>;
 }
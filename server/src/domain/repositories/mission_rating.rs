@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity},
+    value_objects::mission_rating_model::ReputationModel,
+};
+
+#[async_trait]
+pub trait MissionRatingRepository {
+    async fn rate(&self, add_mission_rating_entity: AddMissionRatingEntity) -> Result<i32>;
+    async fn get_received(&self, brawler_id: i32) -> Result<Vec<MissionRatingEntity>>;
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel>;
+    /// Reputations of several brawlers at once. Brawlers nobody has rated
+    /// yet have no entry.
+    async fn get_reputations(&self, brawler_ids: Vec<i32>) -> Result<Vec<ReputationModel>>;
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::{
+    mission_series::{AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity},
+    missions::{AddMissionEntity, MissionEntity},
+};
+
+#[async_trait]
+pub trait MissionSeriesRepository {
+    async fn create(&self, add_mission_series_entity: AddMissionSeriesEntity) -> Result<i32>;
+    async fn get_one(&self, series_id: i32) -> Result<MissionSeriesEntity>;
+    async fn get_by_chief(&self, chief_id: i32) -> Result<Vec<MissionSeriesEntity>>;
+    async fn get_occurrences(&self, series_id: i32) -> Result<Vec<MissionEntity>>;
+    /// A new `next_occurrence_at` is only written while the series is still at
+    /// `occurrence`; returns `false` when the scheduler moved it on meanwhile or
+    /// the series has been cancelled.
+    async fn edit(
+        &self,
+        series_id: i32,
+        occurrence: Option<NaiveDateTime>,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+    ) -> Result<bool>;
+    async fn cancel(&self, series_id: i32) -> Result<()>;
+    async fn get_due(&self, until: NaiveDateTime) -> Result<Vec<MissionSeriesEntity>>;
+    /// Creates the mission for `occurrence` and moves the series on to
+    /// `next_occurrence_at`. Returns `None` when the series already moved past
+    /// `occurrence`, so two schedulers never create the same occurrence twice.
+    async fn materialize(
+        &self,
+        series_id: i32,
+        occurrence: NaiveDateTime,
+        next_occurrence_at: Option<NaiveDateTime>,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<Option<i32>>;
+    /// Moves the series past `occurrence` without creating a mission.
+    async fn skip(
+        &self,
+        series_id: i32,
+        occurrence: NaiveDateTime,
+        next_occurrence_at: Option<NaiveDateTime>,
+    ) -> Result<bool>;
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::mission_templates::{AddMissionTemplateEntity, MissionTemplateEntity};
+
+#[async_trait]
+pub trait MissionTemplateRepository {
+    async fn create(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<i32>;
+    async fn get_one(&self, template_id: i32) -> Result<MissionTemplateEntity>;
+    async fn get_by_owner(&self, owner_id: i32) -> Result<Vec<MissionTemplateEntity>>;
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()>;
+}
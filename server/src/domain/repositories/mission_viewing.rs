@@ -2,8 +2,14 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::domain::{
-    entities::missions::MissionEntity,
-    value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+    entities::{
+        crew_memberships::CrewMemberShips, mission_revisions::MissionRevisionEntity,
+        mission_role_slots::MissionRoleSlotEntity, missions::MissionEntity,
+    },
+    value_objects::{
+        brawler_model::BrawlerModel, mission_filter::MissionFilter,
+        recommendation_model::RecommendationSignalModel,
+    },
 };
 
 #[async_trait]
@@ -11,5 +17,27 @@ pub trait MissionViewingRepository {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
     async fn get_one(&self, mission_id: i32) -> Result<MissionEntity>;
     async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionEntity>>;
+    /// Ranked full-text search; each mission comes with a highlighted snippet.
+    async fn search(
+        &self,
+        mission_filter: &MissionFilter,
+        q: &str,
+    ) -> Result<Vec<(MissionEntity, String)>>;
+    async fn autocomplete(&self, terms: &[String], limit: i64) -> Result<Vec<(i32, String)>>;
     async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
+    async fn get_crew_ids(&self, mission_id: i32) -> Result<Vec<i32>>;
+    /// Signals of every Open mission with a free place that `brawler_id` could
+    /// still join; ranking them is left to the caller.
+    async fn get_recommendation_signals(
+        &self,
+        brawler_id: i32,
+        max_crew_per_mission: i64,
+    ) -> Result<Vec<RecommendationSignalModel>>;
+    async fn get_crew_memberships(&self, mission_id: i32) -> Result<Vec<CrewMemberShips>>;
+    async fn get_role_slots(&self, mission_id: i32) -> Result<Vec<MissionRoleSlotEntity>>;
+    async fn get_followed(&self, brawler_id: i32) -> Result<Vec<MissionEntity>>;
+    async fn get_revisions(&self, mission_id: i32) -> Result<Vec<MissionRevisionEntity>>;
+    /// Percentage of objectives done; `None` when the mission has no objectives.
+    async fn objective_progress(&self, mission_id: i32) -> Result<Option<i32>>;
+    async fn pending_required_objectives(&self, mission_id: i32) -> Result<i64>;
 }
@@ -1,6 +1,19 @@
+pub mod achievement;
+pub mod activity;
 pub mod brawlers;
 pub mod crew_operation;
+pub mod guild;
+pub mod image_storage;
+pub mod leaderboard;
+pub mod matchmaking;
+pub mod mission_attachment;
 pub mod mission_management;
+pub mod mission_objective;
 pub mod mission_operation;
+pub mod mission_rating;
+pub mod mission_series;
+pub mod mission_template;
 pub mod mission_viewing;
+pub mod points_ledger;
+pub mod social;
 // pub mod transaction_provider;
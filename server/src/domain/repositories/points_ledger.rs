@@ -0,0 +1,23 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::points_ledger::{AddPointsLedgerEntity, PointsLedgerEntity};
+
+#[async_trait]
+pub trait PointsLedgerRepository {
+    async fn append(&self, entries: Vec<AddPointsLedgerEntity>) -> Result<usize>;
+    async fn get_by_source(
+        &self,
+        source: &str,
+        source_id: i32,
+        source_at: NaiveDateTime,
+    ) -> Result<Vec<PointsLedgerEntity>>;
+    async fn get_total(&self, brawler_id: i32) -> Result<i64>;
+    async fn get_history(
+        &self,
+        brawler_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<PointsLedgerEntity>>;
+}
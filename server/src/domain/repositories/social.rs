@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{brawler_blocks::BrawlerBlockEntity, brawler_follows::BrawlerFollowEntity},
+    value_objects::social_model::SocialBrawlerModel,
+};
+
+#[async_trait]
+pub trait SocialRepository {
+    async fn follow(&self, brawler_follow_entity: BrawlerFollowEntity) -> Result<()>;
+    async fn unfollow(&self, brawler_follow_entity: BrawlerFollowEntity) -> Result<()>;
+    async fn get_following(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>>;
+    async fn get_followers(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>>;
+    async fn get_friends(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>>;
+    async fn block(&self, brawler_block_entity: BrawlerBlockEntity) -> Result<()>;
+    async fn unblock(&self, brawler_block_entity: BrawlerBlockEntity) -> Result<()>;
+    async fn get_blocked(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>>;
+    async fn is_blocked_between(&self, brawler_id: i32, other_id: i32) -> Result<bool>;
+}
@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Timestamp, VarChar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    MissionCreated,
+    MissionStarted,
+    MissionCompleted,
+    MissionFailed,
+    CrewJoined,
+    CrewLeft,
+}
+
+impl Display for ActivityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActivityKind::MissionCreated => write!(f, "mission_created"),
+            ActivityKind::MissionStarted => write!(f, "mission_started"),
+            ActivityKind::MissionCompleted => write!(f, "mission_completed"),
+            ActivityKind::MissionFailed => write!(f, "mission_failed"),
+            ActivityKind::CrewJoined => write!(f, "crew_joined"),
+            ActivityKind::CrewLeft => write!(f, "crew_left"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct ActivityEventModel {
+    #[diesel(sql_type=Integer)]
+    pub id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub kind: String,
+    #[diesel(sql_type=Integer)]
+    pub actor_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub actor_display_name: String,
+    #[diesel(sql_type=Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub mission_name: String,
+    #[diesel(sql_type=Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ActivityFilter {
+    pub before: Option<i32>,
+    pub limit: Option<i64>,
+}
@@ -1,29 +1,45 @@
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
+
+use super::upload_file::{MAX_FILE_BYTES, UploadFile};
+
+pub const IMAGE_MIME_TYPES: [&str; 2] = ["image/png", "image/jpeg"];
+pub const AVATAR_MIME_TYPES: [&str; 4] = ["image/png", "image/jpeg", "image/webp", "image/gif"];
+pub const ATTACHMENT_MIME_TYPES: [&str; 3] = ["image/png", "image/jpeg", "application/pdf"];
+
 #[derive(Debug, Clone)]
-pub struct Base64Img(String);
+pub struct Base64Img {
+    file: UploadFile,
+}
 
 impl Base64Img {
-    pub fn into_inner(self) -> String {
-        self.0
+    pub fn into_file(self) -> UploadFile {
+        self.file
     }
 
     pub fn new(data: String) -> Result<Self> {
+        Self::with_types(data, &IMAGE_MIME_TYPES)
+    }
+
+    /// Same checks as `new`, but only the given mime types are accepted.
+    pub fn with_types(data: String, mime_types: &[&str]) -> Result<Self> {
         if data.is_empty() {
             return Err(anyhow::anyhow!("data can not be empty !!"));
         }
+        // Refuse oversized payloads before decoding them.
+        if data.len() / 4 * 3 > MAX_FILE_BYTES + 2 {
+            return Err(anyhow::anyhow!(
+                "file is too large, at most {} MB !!",
+                MAX_FILE_BYTES / 1024 / 1024
+            ));
+        }
         let bytes = match general_purpose::STANDARD.decode(&data) {
             Ok(bs) => bs,
             Err(_) => return Err(anyhow::anyhow!("invalid img data !!")),
         };
-        let file_type = match infer::get(&bytes) {
-            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
-                t.mime_type()
-            }
-            _ => return Err(anyhow::anyhow!("un-support file type")),
-        };
 
-        let base64text = format!("data:{};base64,{}", file_type, data);
-        Ok(Self(base64text))
+        Ok(Self {
+            file: UploadFile::new(bytes, mime_types)?,
+        })
     }
 }
@@ -1,10 +1,22 @@
+use chrono::NaiveDateTime;
 use diesel::{
     prelude::QueryableByName,
-    sql_types::{Integer, VarChar},
+    sql_types::{BigInt, Double, Integer, Nullable, Timestamp, VarChar},
 };
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::RegisterBrawlerEntity, value_objects::badge_model::AwardedBadgeModel,
+};
+
+/// Served for brawlers without an uploaded avatar; the identicon is derived from the id.
+pub fn default_avatar_url(brawler_id: i32) -> String {
+    format!("/api/brawler/{}/avatar/default", brawler_id)
+}
+
+pub fn avatar_or_default(avatar_url: Option<String>, brawler_id: i32) -> Option<String> {
+    avatar_url.or_else(|| Some(default_avatar_url(brawler_id)))
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -25,12 +37,57 @@ impl RegisterBrawlerModel {
 
 #[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
 pub struct BrawlerModel {
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
     #[diesel(sql_type=VarChar)]
     pub display_name: String,
-    #[diesel(sql_type=VarChar)]
-    pub avatar_url: String,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub avatar_url: Option<String>,
     #[diesel(sql_type=Integer)]
     pub mission_success_count: i32,
     #[diesel(sql_type=Integer)]
     pub mission_join_count: i32,
+    #[diesel(sql_type=Double)]
+    pub reputation_score: f64,
+    #[diesel(sql_type=BigInt)]
+    pub rating_count: i64,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct BrawlerProfileModel {
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub display_name: String,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type=Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type=Double)]
+    pub reputation_score: f64,
+    #[diesel(sql_type=BigInt)]
+    pub rating_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicProfileModel {
+    #[serde(flatten)]
+    pub profile: BrawlerProfileModel,
+    pub badges: Vec<AwardedBadgeModel>,
+    pub avatar_variants: Vec<AvatarVariantModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarVariantModel {
+    pub size: i32,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarModel {
+    pub url: String,
+    pub public_id: String,
+    pub variants: Vec<AvatarVariantModel>,
 }
@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer, Nullable, Timestamp, VarChar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{entities::guilds::AddGuildEntity, value_objects::guild_roles::GuildRoles};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GuildModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_id: i32,
+    pub member_count: i64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddGuildModel {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl AddGuildModel {
+    pub fn to_entity(&self, owner_id: i32) -> AddGuildEntity {
+        AddGuildEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            owner_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChangeGuildRoleModel {
+    pub role: GuildRoles,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct GuildMemberModel {
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub display_name: String,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type=VarChar)]
+    pub role: String,
+    #[diesel(sql_type=Timestamp)]
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct GuildStatsModel {
+    #[diesel(sql_type=Integer)]
+    pub guild_id: i32,
+    #[diesel(sql_type=BigInt)]
+    pub member_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub open_mission_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub in_progress_mission_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub completed_mission_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub failed_mission_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub member_completed_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub member_failed_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GuildInvitationModel {
+    pub guild_id: i32,
+    pub guild_name: String,
+    pub invited_by: i32,
+    pub created_at: NaiveDateTime,
+}
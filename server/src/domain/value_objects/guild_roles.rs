@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum GuildRoles {
+    Owner,
+    Officer,
+    #[default]
+    Member,
+}
+
+impl Display for GuildRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuildRoles::Owner => write!(f, "Owner"),
+            GuildRoles::Officer => write!(f, "Officer"),
+            GuildRoles::Member => write!(f, "Member"),
+        }
+    }
+}
+
+impl GuildRoles {
+    pub fn can_manage(role: &str) -> bool {
+        role == GuildRoles::Owner.to_string() || role == GuildRoles::Officer.to_string()
+    }
+}
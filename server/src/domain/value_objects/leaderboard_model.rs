@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Double, Integer, Nullable, VarChar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardMetric {
+    #[default]
+    Completed,
+    SuccessRate,
+    Led,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LeaderboardFilter {
+    pub metric: Option<LeaderboardMetric>,
+    pub season: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct LeaderboardEntryModel {
+    #[diesel(sql_type=BigInt)]
+    pub rank: i64,
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub display_name: String,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type=Integer)]
+    pub completed_count: i32,
+    #[diesel(sql_type=Integer)]
+    pub failed_count: i32,
+    #[diesel(sql_type=Integer)]
+    pub led_count: i32,
+    #[diesel(sql_type=Double)]
+    pub success_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeasonModel {
+    pub id: i32,
+    pub name: String,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
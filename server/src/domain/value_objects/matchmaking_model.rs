@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::matchmaking_tickets::AddMatchmakingTicketEntity;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MatchmakingStatuses {
+    #[default]
+    Queued,
+    Proposed,
+}
+
+impl Display for MatchmakingStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchmakingStatuses::Queued => write!(f, "Queued"),
+            MatchmakingStatuses::Proposed => write!(f, "Proposed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MatchmakingTicketModel {
+    pub brawler_id: i32,
+    pub category: Option<String>,
+    pub role: Option<String>,
+    pub min_reputation: Option<f64>,
+    pub status: String,
+    pub mission_id: Option<i32>,
+    pub proposed_at: Option<NaiveDateTime>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Preferences of a brawler waiting to be placed. `category` matches a mission
+/// tag, `role` a role slot and `min_reputation` the reputation of the chief.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EnqueueModel {
+    pub category: Option<String>,
+    pub role: Option<String>,
+    pub min_reputation: Option<f64>,
+}
+
+impl EnqueueModel {
+    pub fn to_entity(&self, brawler_id: i32) -> AddMatchmakingTicketEntity {
+        AddMatchmakingTicketEntity {
+            brawler_id,
+            category: self.category.clone(),
+            role: self.role.clone(),
+            min_reputation: self.min_reputation,
+            status: MatchmakingStatuses::Queued.to_string(),
+        }
+    }
+}
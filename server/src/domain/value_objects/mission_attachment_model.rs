@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionAttachmentModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub file_name: Option<String>,
+    pub mime_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadAttachmentModel {
+    pub base64_string: String,
+    pub file_name: Option<String>,
+}
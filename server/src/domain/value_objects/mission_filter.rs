@@ -6,4 +6,16 @@ use crate::domain::value_objects::mission_statuses::MissionStatuses;
 pub struct MissionFilter {
     pub name: Option<String>,
     pub status: Option<MissionStatuses>,
+    pub q: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AutocompleteFilter {
+    pub q: String,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RecommendationFilter {
+    pub limit: Option<i64>,
 }
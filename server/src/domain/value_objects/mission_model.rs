@@ -1,11 +1,15 @@
+use anyhow::Result;
 use chrono::NaiveDateTime;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::domain::{
     entities::missions::{AddMissionEntity, EditMissionEntity},
     value_objects::mission_statuses::MissionStatuses,
 };
 
+const MAX_TAGS_PER_MISSION: usize = 10;
+const MAX_TAG_LENGTH: usize = 30;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MissionModel {
     pub id: i32,
@@ -14,14 +18,60 @@ pub struct MissionModel {
     pub status: String,
     pub chief_id: i32,
     pub crew_count: i64,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub series_id: Option<i32>,
+    pub scheduled_for: Option<NaiveDateTime>,
+    pub version: i32,
+    pub consensus_outcome: bool,
+    pub outcome_overridden_at: Option<NaiveDateTime>,
+    pub require_objectives: bool,
+    /// Percentage of objectives done; `None` when the mission has no objectives.
+    pub progress: Option<i32>,
+    pub banner_url: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionSuggestionModel {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeletedMissionModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+    pub purge_at: NaiveDateTime,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    #[serde(default)]
+    pub guild_only: bool,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub consensus_outcome: bool,
+    #[serde(default)]
+    pub require_objectives: bool,
 }
 
 impl AddMissionModel {
@@ -31,6 +81,15 @@ impl AddMissionModel {
             description: self.description.clone(),
             status: MissionStatuses::Open.to_string(),
             chief_id,
+            min_reputation: self.min_reputation,
+            guild_id: self.guild_id,
+            guild_only: self.guild_only,
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+            series_id: None,
+            scheduled_for: None,
+            consensus_outcome: self.consensus_outcome,
+            require_objectives: self.require_objectives,
         }
     }
 }
@@ -39,14 +98,116 @@ impl AddMissionModel {
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// Missing leaves the minimum as is, `null` removes it.
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub min_reputation: Option<Option<f64>>,
+    /// Missing leaves the capacity as is, `null` falls back to the default.
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub max_crew: Option<Option<i32>>,
+    pub tags: Option<Vec<String>>,
+    pub consensus_outcome: Option<bool>,
+    pub require_objectives: Option<bool>,
 }
 
 impl EditMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> EditMissionEntity {
+    pub fn to_entity(&self) -> EditMissionEntity {
         EditMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            chief_id,
+            min_reputation: self.min_reputation,
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+            consensus_outcome: self.consensus_outcome,
+            require_objectives: self.require_objectives,
+        }
+    }
+}
+
+/// Tells an explicit `null` (`Some(None)`) apart from a missing field (`None`).
+fn explicit_null<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CloneMissionModel {
+    #[serde(default)]
+    pub reinvite_crew: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionInvitationModel {
+    pub mission_id: i32,
+    pub mission_name: String,
+    pub invited_by: i32,
+    pub created_at: NaiveDateTime,
+}
+
+pub fn check_max_crew(max_crew: Option<i32>) -> Result<()> {
+    let Some(max_crew) = max_crew else {
+        return Ok(());
+    };
+
+    let max_crew_per_mission: i32 = std::env::var("MAX_CREW_PER_MISSION")
+        .expect("missing value")
+        .parse()?;
+    if max_crew < 1 || max_crew > max_crew_per_mission {
+        return Err(anyhow::anyhow!(
+            "Mission capacity must be between 1 and {}",
+            max_crew_per_mission
+        ));
+    }
+    Ok(())
+}
+
+pub fn normalize_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() || result.contains(&tag) {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Tags must be at most {} characters long",
+                MAX_TAG_LENGTH
+            ));
         }
+        result.push(tag);
+    }
+
+    if result.len() > MAX_TAGS_PER_MISSION {
+        return Err(anyhow::anyhow!(
+            "A mission can have at most {} tags",
+            MAX_TAGS_PER_MISSION
+        ));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(json: &str) -> EditMissionModel {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn edit_tells_null_from_missing() {
+        assert_eq!(edit("{}").min_reputation, None);
+        assert_eq!(
+            edit(r#"{"min_reputation":null}"#).min_reputation,
+            Some(None)
+        );
+        assert_eq!(
+            edit(r#"{"min_reputation":3.5}"#).min_reputation,
+            Some(Some(3.5))
+        );
+        assert_eq!(edit("{}").max_crew, None);
+        assert_eq!(edit(r#"{"max_crew":null}"#).max_crew, Some(None));
     }
 }
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_objectives::{
+    AddMissionObjectiveEntity, EditMissionObjectiveEntity,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionObjectiveModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub required: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionObjectiveModel {
+    pub title: String,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+impl AddMissionObjectiveModel {
+    pub fn to_entity(&self, mission_id: i32, position: i32) -> AddMissionObjectiveEntity {
+        AddMissionObjectiveEntity {
+            mission_id,
+            position,
+            title: self.title.clone(),
+            required: self.required,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMissionObjectiveModel {
+    pub title: Option<String>,
+    pub required: Option<bool>,
+}
+
+impl EditMissionObjectiveModel {
+    pub fn to_entity(&self) -> EditMissionObjectiveEntity {
+        EditMissionObjectiveEntity {
+            title: self.title.clone(),
+            required: self.required,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorderObjectivesModel {
+    pub objective_ids: Vec<i32>,
+}
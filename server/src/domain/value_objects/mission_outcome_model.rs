@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_outcome_votes::AddMissionOutcomeVoteEntity,
+    value_objects::mission_statuses::MissionStatuses,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddOutcomeVoteModel {
+    pub outcome: MissionStatuses,
+}
+
+impl AddOutcomeVoteModel {
+    pub fn to_entity(&self, mission_id: i32, brawler_id: i32) -> AddMissionOutcomeVoteEntity {
+        AddMissionOutcomeVoteEntity {
+            mission_id,
+            brawler_id,
+            outcome: self.outcome.to_string(),
+        }
+    }
+}
+
+/// Votes of the current crew; `required_votes` is the quorum for a consensus mission.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutcomeTallyModel {
+    pub mission_id: i32,
+    pub status: String,
+    pub consensus_outcome: bool,
+    pub crew_count: i64,
+    pub required_votes: i64,
+    pub completed_votes: i64,
+    pub failed_votes: i64,
+}
@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Double, Integer},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_ratings::AddMissionRatingEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionRatingModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMissionRatingModel {
+    pub ratee_id: i32,
+    pub score: i32,
+    pub comment: Option<String>,
+}
+
+impl AddMissionRatingModel {
+    pub fn to_entity(&self, mission_id: i32, rater_id: i32) -> AddMissionRatingEntity {
+        AddMissionRatingEntity {
+            mission_id,
+            rater_id,
+            ratee_id: self.ratee_id,
+            score: self.score,
+            comment: self.comment.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct ReputationModel {
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type=Double)]
+    pub reputation_score: f64,
+    #[diesel(sql_type=BigInt)]
+    pub rating_count: i64,
+}
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Name and description a mission had at `version`, replaced by `edited_by` at `edited_at`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionRevisionModel {
+    pub version: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub edited_by: i32,
+    pub edited_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RevisionDiffFilter {
+    pub from: Option<i32>,
+    pub to: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChangeModel {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionRevisionDiffModel {
+    pub mission_id: i32,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub changes: Vec<FieldChangeModel>,
+}
+
+/// Returned when an edit carries a version precondition that no longer holds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VersionConflict {
+    pub current_version: i32,
+}
+
+impl Display for VersionConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Mission has been modified, current version is {}",
+            self.current_version
+        )
+    }
+}
+
+impl std::error::Error for VersionConflict {}
+
+/// Returned when a diff asks for a version the mission never had.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RevisionNotFound {
+    pub version: i32,
+}
+
+impl Display for RevisionNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Revision {} not found", self.version)
+    }
+}
+
+impl std::error::Error for RevisionNotFound {}
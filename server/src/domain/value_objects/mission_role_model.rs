@@ -0,0 +1,80 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_role_slots::MissionRoleSlotEntity;
+
+const MAX_ROLE_SLOTS_PER_MISSION: usize = 20;
+const MAX_ROLE_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RoleSlotModel {
+    pub role: String,
+    pub capacity: i32,
+    pub filled: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddRoleSlotModel {
+    pub role: String,
+    pub capacity: i32,
+}
+
+impl AddRoleSlotModel {
+    pub fn to_entity(&self, mission_id: i32) -> MissionRoleSlotEntity {
+        MissionRoleSlotEntity {
+            mission_id,
+            role: self.role.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRoleSlotsModel {
+    pub slots: Vec<AddRoleSlotModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CrewRoleQuery {
+    pub role: Option<String>,
+}
+
+/// Roles are compared case-insensitively, so "Tank" and "tank" are the same slot.
+pub fn normalize_role(role: &str) -> Result<String> {
+    let role = role.trim().to_lowercase();
+    if role.is_empty() {
+        return Err(anyhow::anyhow!("Role can not be empty"));
+    }
+    if role.chars().count() > MAX_ROLE_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Role must be at most {} characters long",
+            MAX_ROLE_LENGTH
+        ));
+    }
+    Ok(role)
+}
+
+pub fn normalize_role_slots(slots: &[AddRoleSlotModel]) -> Result<Vec<AddRoleSlotModel>> {
+    if slots.len() > MAX_ROLE_SLOTS_PER_MISSION {
+        return Err(anyhow::anyhow!(
+            "A mission can have at most {} roles",
+            MAX_ROLE_SLOTS_PER_MISSION
+        ));
+    }
+
+    let mut result: Vec<AddRoleSlotModel> = Vec::new();
+    for slot in slots {
+        let role = normalize_role(&slot.role)?;
+        if slot.capacity < 1 {
+            return Err(anyhow::anyhow!("Role {} must have at least one slot", role));
+        }
+        if result.iter().any(|existing| existing.role == role) {
+            return Err(anyhow::anyhow!("Role {} is listed more than once", role));
+        }
+        result.push(AddRoleSlotModel {
+            role,
+            capacity: slot.capacity,
+        });
+    }
+    Ok(result)
+}
@@ -0,0 +1,228 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDateTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RecurrenceFrequency {
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceFrequency::Daily => write!(f, "Daily"),
+            RecurrenceFrequency::Weekly => write!(f, "Weekly"),
+        }
+    }
+}
+
+impl RecurrenceFrequency {
+    pub fn try_from(frequency: &str) -> Result<Self> {
+        match frequency {
+            "Daily" => Ok(RecurrenceFrequency::Daily),
+            "Weekly" => Ok(RecurrenceFrequency::Weekly),
+            _ => Err(anyhow::anyhow!("Invalid recurrence frequency")),
+        }
+    }
+}
+
+/// Occurrences happen at the time of day of `starts_at`, on every day for
+/// `Daily` or on the listed weekdays for `Weekly`, until `ends_at` or
+/// `max_occurrences` is reached, whichever comes first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: Vec<Weekday>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+}
+
+impl RecurrenceRule {
+    /// First occurrence strictly after `after`, or `None` once the rule is
+    /// exhausted. `occurrence_count` is how many occurrences were already used.
+    pub fn next_after(&self, after: NaiveDateTime, occurrence_count: i32) -> Option<NaiveDateTime> {
+        if self
+            .max_occurrences
+            .is_some_and(|max_occurrences| occurrence_count >= max_occurrences)
+        {
+            return None;
+        }
+
+        let time = self.starts_at.time();
+        let mut date = after.date().max(self.starts_at.date());
+
+        // A week plus one day always contains the next matching weekday.
+        for _ in 0..8 {
+            let candidate = date.and_time(time);
+            if candidate > after && candidate >= self.starts_at && self.matches(date.weekday()) {
+                return match self.ends_at {
+                    Some(ends_at) if candidate > ends_at => None,
+                    _ => Some(candidate),
+                };
+            }
+            date = date.succ_opt()?;
+        }
+
+        None
+    }
+
+    /// First occurrence that is not in the past.
+    pub fn first_from(&self, now: NaiveDateTime, occurrence_count: i32) -> Option<NaiveDateTime> {
+        let after = now.max(self.starts_at - Duration::seconds(1));
+        self.next_after(after, occurrence_count)
+    }
+
+    fn matches(&self, weekday: Weekday) -> bool {
+        match self.frequency {
+            RecurrenceFrequency::Daily => true,
+            RecurrenceFrequency::Weekly => self.weekdays.contains(&weekday),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionSeriesModel {
+    pub id: i32,
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    pub guild_only: bool,
+    pub frequency: String,
+    pub weekdays: Vec<Weekday>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+    pub occurrence_count: i32,
+    pub next_occurrence_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddMissionSeriesModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub min_reputation: Option<f64>,
+    pub guild_id: Option<i32>,
+    #[serde(default)]
+    pub guild_only: bool,
+    pub frequency: RecurrenceFrequency,
+    #[serde(default)]
+    pub weekdays: Vec<Weekday>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+}
+
+impl AddMissionSeriesModel {
+    pub fn to_rule(&self) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: self.frequency,
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            max_occurrences: self.max_occurrences,
+        }
+    }
+}
+
+/// Changes apply to occurrences that have not been created yet; missions that
+/// already exist are edited through mission management like any other.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EditMissionSeriesModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub weekdays: Option<Vec<Weekday>>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub max_occurrences: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .unwrap()
+    }
+
+    // 2026-10-19 is a Monday.
+    fn rule(frequency: RecurrenceFrequency, weekdays: Vec<Weekday>) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency,
+            weekdays,
+            starts_at: at(19, 18),
+            ends_at: None,
+            max_occurrences: None,
+        }
+    }
+
+    #[test]
+    fn next_after_is_strictly_after() {
+        let rule = rule(RecurrenceFrequency::Daily, vec![]);
+
+        assert_eq!(rule.next_after(at(20, 12), 0), Some(at(20, 18)));
+        assert_eq!(rule.next_after(at(20, 18), 1), Some(at(21, 18)));
+    }
+
+    #[test]
+    fn next_after_wraps_to_next_week() {
+        let rule = rule(
+            RecurrenceFrequency::Weekly,
+            vec![Weekday::Mon, Weekday::Fri],
+        );
+
+        assert_eq!(rule.next_after(at(20, 12), 1), Some(at(23, 18)));
+        assert_eq!(rule.next_after(at(23, 18), 2), Some(at(26, 18)));
+    }
+
+    #[test]
+    fn next_after_stops_at_ends_at() {
+        let mut rule = rule(RecurrenceFrequency::Daily, vec![]);
+        rule.ends_at = Some(at(21, 18));
+
+        assert_eq!(rule.next_after(at(20, 18), 1), Some(at(21, 18)));
+        assert_eq!(rule.next_after(at(21, 18), 2), None);
+    }
+
+    #[test]
+    fn next_after_stops_at_max_occurrences() {
+        let mut rule = rule(RecurrenceFrequency::Daily, vec![]);
+        rule.max_occurrences = Some(2);
+
+        assert_eq!(rule.next_after(at(20, 18), 1), Some(at(21, 18)));
+        assert_eq!(rule.next_after(at(20, 18), 2), None);
+    }
+
+    #[test]
+    fn first_from_includes_starts_at() {
+        let rule = rule(RecurrenceFrequency::Weekly, vec![Weekday::Mon]);
+
+        assert_eq!(rule.first_from(at(1, 0), 0), Some(at(19, 18)));
+    }
+
+    #[test]
+    fn first_from_skips_the_past() {
+        let rule = rule(RecurrenceFrequency::Weekly, vec![Weekday::Mon]);
+
+        assert_eq!(rule.first_from(at(19, 19), 0), Some(at(26, 18)));
+    }
+}
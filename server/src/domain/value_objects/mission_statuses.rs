@@ -21,3 +21,21 @@ impl Display for MissionStatuses {
         }
     }
 }
+
+/// Returned when a mission's status changed between reading it and updating it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusConflict {
+    pub mission_id: i32,
+}
+
+impl Display for StatusConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Status of mission {} has already changed",
+            self.mission_id
+        )
+    }
+}
+
+impl std::error::Error for StatusConflict {}
@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::mission_templates::AddMissionTemplateEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionTemplateModel {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddMissionTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl AddMissionTemplateModel {
+    pub fn to_entity(&self, owner_id: i32) -> AddMissionTemplateEntity {
+        AddMissionTemplateEntity {
+            owner_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            max_crew: self.max_crew,
+            tags: self.tags.clone(),
+        }
+    }
+}
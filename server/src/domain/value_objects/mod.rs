@@ -1,6 +1,25 @@
+pub mod activity_model;
+pub mod badge_model;
 pub mod base64_img;
 pub mod brawler_model;
+pub mod guild_model;
+pub mod guild_roles;
+pub mod leaderboard_model;
+pub mod matchmaking_model;
+pub mod mission_attachment_model;
 pub mod mission_filter;
 pub mod mission_model;
+pub mod mission_objective_model;
+pub mod mission_outcome_model;
+pub mod mission_rating_model;
+pub mod mission_revision_model;
+pub mod mission_role_model;
+pub mod mission_series_model;
 pub mod mission_statuses;
+pub mod mission_template_model;
+pub mod progression_model;
+pub mod recommendation_model;
+pub mod social_model;
+pub mod upload_file;
 pub mod uploaded_img;
+pub mod upstream_error;
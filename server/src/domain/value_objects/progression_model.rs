@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PointsSource {
+    MissionCompleted,
+    MissionFailed,
+    Correction,
+    OutcomeReversal,
+}
+
+impl Display for PointsSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointsSource::MissionCompleted => write!(f, "mission_completed"),
+            PointsSource::MissionFailed => write!(f, "mission_failed"),
+            PointsSource::Correction => write!(f, "correction"),
+            PointsSource::OutcomeReversal => write!(f, "outcome_reversal"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PointsLedgerModel {
+    pub id: i32,
+    pub source: String,
+    pub source_id: Option<i32>,
+    pub points: i32,
+    pub note: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PointsHistoryFilter {
+    pub before: Option<i32>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointsCorrectionModel {
+    pub brawler_id: i32,
+    pub points: i32,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressionModel {
+    pub brawler_id: i32,
+    pub total_xp: i64,
+    pub level: i32,
+    pub next_level_xp: Option<i64>,
+}
+
+impl ProgressionModel {
+    /// Level 1 starts at zero; each threshold reached adds a level.
+    pub fn new(brawler_id: i32, total_xp: i64, level_thresholds: &[i64]) -> Self {
+        let reached = level_thresholds
+            .iter()
+            .filter(|threshold| total_xp >= **threshold)
+            .count();
+        let next_level_xp = level_thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| total_xp < *threshold)
+            .min();
+
+        Self {
+            brawler_id,
+            total_xp,
+            level: reached as i32 + 1,
+            next_level_xp,
+        }
+    }
+}
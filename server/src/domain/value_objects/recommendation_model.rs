@@ -0,0 +1,34 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Double, Integer, Nullable, Timestamp},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_model::MissionModel;
+
+/// Raw signals of an Open mission with free places, as seen by one brawler.
+#[derive(Debug, Clone, QueryableByName)]
+pub struct RecommendationSignalModel {
+    #[diesel(sql_type=Integer)]
+    pub mission_id: i32,
+    /// Share of the brawler's completed missions that carried one of this
+    /// mission's tags, between 0 and 1.
+    #[diesel(sql_type=Double)]
+    pub tag_affinity: f64,
+    /// Followed brawlers leading or crewing the mission.
+    #[diesel(sql_type=BigInt)]
+    pub followee_count: i64,
+    /// Average rating of the chief, `None` while unrated.
+    #[diesel(sql_type=Nullable<Double>)]
+    pub chief_reputation: Option<f64>,
+    #[diesel(sql_type=Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecommendedMissionModel {
+    pub mission: MissionModel,
+    pub score: f64,
+    pub reasons: Vec<String>,
+}
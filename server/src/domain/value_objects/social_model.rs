@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Nullable, Timestamp, VarChar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+pub struct SocialBrawlerModel {
+    #[diesel(sql_type=Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type=VarChar)]
+    pub display_name: String,
+    #[diesel(sql_type=Nullable<VarChar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type=Timestamp)]
+    pub since: NaiveDateTime,
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+
+pub const MAX_FILE_BYTES: usize = 5 * 1024 * 1024;
+
+/// A decoded file whose type was sniffed from its content, ready for the image store.
+#[derive(Debug, Clone)]
+pub struct UploadFile {
+    bytes: Vec<u8>,
+    mime_type: &'static str,
+}
+
+impl UploadFile {
+    pub fn new(bytes: Vec<u8>, mime_types: &[&str]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(anyhow::anyhow!("data can not be empty !!"));
+        }
+        if bytes.len() > MAX_FILE_BYTES {
+            return Err(anyhow::anyhow!(
+                "file is too large, at most {} MB !!",
+                MAX_FILE_BYTES / 1024 / 1024
+            ));
+        }
+        let mime_type = Self::sniff(&bytes, mime_types)?;
+
+        Ok(Self { bytes, mime_type })
+    }
+
+    /// Detects the file type from its first bytes, accepting only `mime_types`.
+    pub fn sniff(bytes: &[u8], mime_types: &[&str]) -> Result<&'static str> {
+        match infer::get(bytes) {
+            Some(t) if mime_types.contains(&t.mime_type()) => Ok(t.mime_type()),
+            _ => Err(anyhow::anyhow!("un-support file type")),
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        self.mime_type
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// File extension matching the detected mime type.
+    pub fn extension(&self) -> &'static str {
+        match self.mime_type {
+            "image/png" => "png",
+            "image/jpeg" => "jpg",
+            "image/webp" => "webp",
+            "image/gif" => "gif",
+            "application/pdf" => "pdf",
+            _ => "bin",
+        }
+    }
+}
@@ -16,3 +16,12 @@ impl UploadedImg {
 pub struct UploadBase64Img {
     pub base64_string: String,
 }
+
+/// Where and how a file is stored. `transformation` is only honoured by
+/// stores that can transform images on upload (Cloudinary).
+#[derive(Debug, Clone, Default)]
+pub struct UploadImageOptions {
+    pub folder: Option<String>,
+    pub public_id: Option<String>,
+    pub transformation: Option<String>,
+}
@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+/// Returned when an external service (e.g. the image store) rejects or fails a request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpstreamError {
+    pub service: &'static str,
+    pub status: u16,
+    pub message: String,
+}
+
+impl Display for UpstreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} responded with {}: {}",
+            self.service, self.status, self.message
+        )
+    }
+}
+
+impl std::error::Error for UpstreamError {}
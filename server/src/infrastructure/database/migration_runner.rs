@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use diesel::{migration::MigrationSource, pg::Pg};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+use tracing::info;
+
+use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
+
+pub const MIGRATIONS: EmbeddedMigrations =
+    embed_migrations!("src/infrastructure/database/migrations");
+
+/// Compares the database with the migrations built into this binary. Refuses a
+/// database that has migrations this binary does not know about, and applies the
+/// pending ones when `run_pending` is set. Returns the versions still pending.
+pub fn migrate(db_pool: &PgPoolSquad, run_pending: bool) -> Result<Vec<String>> {
+    let mut conn = db_pool.get()?;
+
+    let known = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!(e))?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect::<HashSet<String>>();
+    let unknown = conn
+        .applied_migrations()
+        .map_err(|e| anyhow::anyhow!(e))?
+        .iter()
+        .map(|version| version.to_string())
+        .filter(|version| !known.contains(version))
+        .collect::<Vec<String>>();
+    if !unknown.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Database schema is ahead of this binary, unknown migrations: {}",
+            unknown.join(", ")
+        ));
+    }
+
+    let pending = conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!(e))?;
+    if !run_pending {
+        return Ok(pending
+            .iter()
+            .map(|migration| migration.name().version().to_string())
+            .collect());
+    }
+
+    for version in conn
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!(e))?
+    {
+        info!("Applied migration {}", version);
+    }
+
+    Ok(Vec::new())
+}
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_ratings;
+
+ALTER TABLE missions
+DROP COLUMN min_reputation,
+DROP COLUMN finished_at;
@@ -0,0 +1,28 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN finished_at TIMESTAMP,
+ADD COLUMN min_reputation DOUBLE PRECISION;
+
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    rater_id INTEGER NOT NULL,
+    ratee_id INTEGER NOT NULL,
+    score INTEGER NOT NULL,
+    "comment" TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT unique_rating_per_pair UNIQUE (mission_id, rater_id, ratee_id),
+    CONSTRAINT check_rating_score CHECK (score BETWEEN 1 AND 5),
+    CONSTRAINT check_rating_not_self CHECK (rater_id <> ratee_id)
+);
+
+ALTER TABLE
+    mission_ratings
+ADD
+    CONSTRAINT fk_rating_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_rating_rater FOREIGN KEY (rater_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_rating_ratee FOREIGN KEY (ratee_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_ratings_ratee_id ON mission_ratings (ratee_id);
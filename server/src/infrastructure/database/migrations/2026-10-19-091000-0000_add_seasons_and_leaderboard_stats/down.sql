@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP MATERIALIZED VIEW IF EXISTS brawler_leaderboard_stats;
+
+DROP TABLE IF EXISTS seasons;
@@ -0,0 +1,42 @@
+-- Your SQL goes here
+CREATE TABLE seasons (
+    id SERIAL PRIMARY KEY,
+    "name" VARCHAR(255) NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_season_range CHECK (starts_at < ends_at)
+);
+
+-- One row per brawler per season, plus an all-time row with season_id = 0.
+-- Refreshed by the server whenever a mission changes status.
+CREATE MATERIALIZED VIEW brawler_leaderboard_stats AS
+WITH participations AS (
+    SELECT cm.brawler_id, m.status, m.finished_at, FALSE AS is_chief
+    FROM crew_memberships cm
+    INNER JOIN missions m ON m.id = cm.mission_id
+    WHERE m.deleted_at IS NULL
+        AND m.status IN ('Completed', 'Failed')
+    UNION ALL
+    SELECT m.chief_id AS brawler_id, m.status, m.finished_at, TRUE AS is_chief
+    FROM missions m
+    WHERE m.deleted_at IS NULL
+        AND m.status IN ('Completed', 'Failed')
+),
+seasoned AS (
+    SELECT p.*, 0 AS season_id
+    FROM participations p
+    UNION ALL
+    SELECT p.*, s.id AS season_id
+    FROM participations p
+    INNER JOIN seasons s ON p.finished_at >= s.starts_at AND p.finished_at < s.ends_at
+)
+SELECT brawler_id,
+        season_id,
+        COUNT(*) FILTER (WHERE status = 'Completed')::INT4 AS completed_count,
+        COUNT(*) FILTER (WHERE status = 'Failed')::INT4 AS failed_count,
+        COUNT(*) FILTER (WHERE is_chief)::INT4 AS led_count
+FROM seasoned
+GROUP BY brawler_id, season_id;
+
+CREATE UNIQUE INDEX idx_brawler_leaderboard_stats_season_brawler ON brawler_leaderboard_stats (season_id, brawler_id);
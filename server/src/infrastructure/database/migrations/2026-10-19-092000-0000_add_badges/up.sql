@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE badges (
+    id SERIAL PRIMARY KEY,
+    code VARCHAR(100) NOT NULL,
+    "name" VARCHAR(255) NOT NULL,
+    "description" TEXT,
+    icon_url VARCHAR(512),
+    metric VARCHAR(100) NOT NULL,
+    threshold INTEGER NOT NULL,
+    window_days INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT unique_badge_code UNIQUE (code),
+    CONSTRAINT check_badge_metric CHECK (
+        metric IN (
+            'missions_completed',
+            'missions_failed',
+            'missions_led',
+            'missions_joined'
+        )
+    ),
+    CONSTRAINT check_badge_threshold CHECK (threshold > 0),
+    CONSTRAINT check_badge_window_days CHECK (window_days IS NULL OR window_days > 0)
+);
+
+CREATE TABLE brawler_badges (
+    brawler_id INTEGER NOT NULL,
+    badge_id INTEGER NOT NULL,
+    awarded_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (brawler_id, badge_id)
+);
+
+ALTER TABLE
+    brawler_badges
+ADD
+    CONSTRAINT fk_brawler_badge_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_brawler_badge_badge FOREIGN KEY (badge_id) REFERENCES badges(id);
+
+INSERT INTO badges (code, "name", "description", metric, threshold, window_days)
+VALUES
+    ('first_mission_joined', 'Recruit', 'Join a mission as a crew member', 'missions_joined', 1, NULL),
+    ('first_mission_completed', 'First Blood', 'Complete your first mission', 'missions_completed', 1, NULL),
+    ('completed_5_in_a_week', 'On a Roll', 'Complete 5 missions within 7 days', 'missions_completed', 5, 7),
+    ('led_10_missions', 'Seasoned Chief', 'Lead 10 missions to the finish', 'missions_led', 10, NULL),
+    ('completed_50_missions', 'Veteran', 'Complete 50 missions', 'missions_completed', 50, NULL);
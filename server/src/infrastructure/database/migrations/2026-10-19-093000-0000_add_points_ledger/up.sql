@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE points_ledger (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    "source" VARCHAR(100) NOT NULL,
+    source_id INTEGER,
+    points INTEGER NOT NULL,
+    note TEXT,
+    issued_by INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    -- Event rows are awarded once; corrections have no source_id and may repeat.
+    CONSTRAINT unique_points_ledger_event UNIQUE (brawler_id, "source", source_id)
+);
+
+ALTER TABLE
+    points_ledger
+ADD
+    CONSTRAINT fk_points_ledger_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_points_ledger_issued_by FOREIGN KEY (issued_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_points_ledger_brawler_id ON points_ledger (brawler_id, id DESC);
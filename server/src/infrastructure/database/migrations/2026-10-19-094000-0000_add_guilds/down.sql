@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP CONSTRAINT check_guild_only_has_guild,
+DROP CONSTRAINT fk_mission_guild,
+DROP COLUMN guild_only,
+DROP COLUMN guild_id;
+
+DROP TABLE IF EXISTS guild_invitations;
+
+DROP TABLE IF EXISTS guild_memberships;
+
+DROP TABLE IF EXISTS guilds;
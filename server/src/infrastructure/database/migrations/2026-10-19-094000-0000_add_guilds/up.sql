@@ -0,0 +1,58 @@
+-- Your SQL goes here
+CREATE TABLE guilds (
+    id SERIAL PRIMARY KEY,
+    "name" VARCHAR(100) NOT NULL,
+    "description" TEXT,
+    owner_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT unique_guild_name UNIQUE ("name")
+);
+
+CREATE TABLE guild_memberships (
+    guild_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    "role" VARCHAR(50) NOT NULL,
+    joined_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (guild_id, brawler_id),
+    CONSTRAINT check_guild_role CHECK ("role" IN ('Owner', 'Officer', 'Member'))
+);
+
+CREATE TABLE guild_invitations (
+    guild_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    invited_by INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (guild_id, brawler_id)
+);
+
+ALTER TABLE
+    guilds
+ADD
+    CONSTRAINT fk_guild_owner FOREIGN KEY (owner_id) REFERENCES brawlers(id);
+
+ALTER TABLE
+    guild_memberships
+ADD
+    CONSTRAINT fk_guild_membership_guild FOREIGN KEY (guild_id) REFERENCES guilds(id),
+ADD
+    CONSTRAINT fk_guild_membership_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+ALTER TABLE
+    guild_invitations
+ADD
+    CONSTRAINT fk_guild_invitation_guild FOREIGN KEY (guild_id) REFERENCES guilds(id),
+ADD
+    CONSTRAINT fk_guild_invitation_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_guild_invitation_invited_by FOREIGN KEY (invited_by) REFERENCES brawlers(id);
+
+ALTER TABLE missions
+ADD COLUMN guild_id INTEGER,
+ADD COLUMN guild_only BOOLEAN NOT NULL DEFAULT FALSE,
+ADD CONSTRAINT fk_mission_guild FOREIGN KEY (guild_id) REFERENCES guilds(id),
+ADD CONSTRAINT check_guild_only_has_guild CHECK (NOT guild_only OR guild_id IS NOT NULL);
+
+CREATE INDEX idx_missions_guild_id ON missions (guild_id);
+
+SELECT diesel_manage_updated_at('guilds');
@@ -0,0 +1,34 @@
+-- Your SQL goes here
+CREATE TABLE brawler_follows (
+    follower_id INTEGER NOT NULL,
+    followee_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (follower_id, followee_id),
+    CONSTRAINT check_follow_not_self CHECK (follower_id <> followee_id)
+);
+
+CREATE TABLE brawler_blocks (
+    blocker_id INTEGER NOT NULL,
+    blocked_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (blocker_id, blocked_id),
+    CONSTRAINT check_block_not_self CHECK (blocker_id <> blocked_id)
+);
+
+ALTER TABLE
+    brawler_follows
+ADD
+    CONSTRAINT fk_follow_follower FOREIGN KEY (follower_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_follow_followee FOREIGN KEY (followee_id) REFERENCES brawlers(id);
+
+ALTER TABLE
+    brawler_blocks
+ADD
+    CONSTRAINT fk_block_blocker FOREIGN KEY (blocker_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_block_blocked FOREIGN KEY (blocked_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_brawler_follows_followee_id ON brawler_follows (followee_id);
+
+CREATE INDEX idx_brawler_blocks_blocked_id ON brawler_blocks (blocked_id);
@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE activity_events (
+    id SERIAL PRIMARY KEY,
+    actor_id INTEGER NOT NULL,
+    mission_id INTEGER NOT NULL,
+    kind VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_activity_kind CHECK (
+        kind IN (
+            'mission_created',
+            'mission_started',
+            'mission_completed',
+            'mission_failed',
+            'crew_joined',
+            'crew_left'
+        )
+    )
+);
+
+ALTER TABLE
+    activity_events
+ADD
+    CONSTRAINT fk_activity_actor FOREIGN KEY (actor_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_activity_mission FOREIGN KEY (mission_id) REFERENCES missions(id);
+
+CREATE INDEX idx_activity_events_actor_id ON activity_events (actor_id);
+
+CREATE INDEX idx_activity_events_mission_id ON activity_events (mission_id);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_missions_search_vector;
+
+ALTER TABLE missions
+DROP COLUMN search_vector;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', coalesce("name", '')), 'A') ||
+    setweight(to_tsvector('english', coalesce("description", '')), 'B')
+) STORED;
+
+CREATE INDEX idx_missions_search_vector ON missions USING GIN (search_vector);
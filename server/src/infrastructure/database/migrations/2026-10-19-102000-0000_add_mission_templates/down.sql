@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_invitations;
+
+DROP TABLE IF EXISTS mission_templates;
+
+ALTER TABLE missions
+DROP CONSTRAINT check_mission_max_crew,
+DROP COLUMN tags,
+DROP COLUMN max_crew;
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN max_crew INTEGER,
+ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}',
+ADD CONSTRAINT check_mission_max_crew CHECK (max_crew IS NULL OR max_crew > 0);
+
+CREATE TABLE mission_templates (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL,
+    "name" VARCHAR(255) NOT NULL,
+    "description" TEXT,
+    max_crew INTEGER,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_template_max_crew CHECK (max_crew IS NULL OR max_crew > 0)
+);
+
+CREATE TABLE mission_invitations (
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    invited_by INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, brawler_id)
+);
+
+ALTER TABLE
+    mission_templates
+ADD
+    CONSTRAINT fk_template_owner FOREIGN KEY (owner_id) REFERENCES brawlers(id);
+
+ALTER TABLE
+    mission_invitations
+ADD
+    CONSTRAINT fk_mission_invitation_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_invitation_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_mission_invitation_invited_by FOREIGN KEY (invited_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_templates_owner_id ON mission_templates (owner_id);
+
+CREATE INDEX idx_mission_invitations_brawler_id ON mission_invitations (brawler_id);
+
+SELECT diesel_manage_updated_at('mission_templates');
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP CONSTRAINT fk_mission_series,
+DROP COLUMN scheduled_for,
+DROP COLUMN series_id;
+
+DROP TABLE IF EXISTS mission_series;
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE mission_series (
+    id SERIAL PRIMARY KEY,
+    chief_id INTEGER NOT NULL,
+    "name" VARCHAR(255) NOT NULL,
+    "description" TEXT,
+    max_crew INTEGER,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    min_reputation DOUBLE PRECISION,
+    guild_id INTEGER,
+    guild_only BOOLEAN NOT NULL DEFAULT FALSE,
+    frequency VARCHAR(50) NOT NULL,
+    weekdays INTEGER[] NOT NULL DEFAULT '{}',
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP,
+    max_occurrences INTEGER,
+    occurrence_count INTEGER NOT NULL DEFAULT 0,
+    next_occurrence_at TIMESTAMP,
+    cancelled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_series_frequency CHECK (frequency IN ('Daily', 'Weekly')),
+    CONSTRAINT check_series_weekdays CHECK (frequency <> 'Weekly' OR cardinality(weekdays) > 0),
+    CONSTRAINT check_series_max_crew CHECK (max_crew IS NULL OR max_crew > 0),
+    CONSTRAINT check_series_max_occurrences CHECK (max_occurrences IS NULL OR max_occurrences > 0)
+);
+
+ALTER TABLE
+    mission_series
+ADD
+    CONSTRAINT fk_series_chief FOREIGN KEY (chief_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_series_guild FOREIGN KEY (guild_id) REFERENCES guilds(id);
+
+ALTER TABLE missions
+ADD COLUMN series_id INTEGER,
+ADD COLUMN scheduled_for TIMESTAMP,
+ADD CONSTRAINT fk_mission_series FOREIGN KEY (series_id) REFERENCES mission_series(id);
+
+CREATE INDEX idx_missions_series_id ON missions (series_id);
+
+CREATE INDEX idx_mission_series_next_occurrence_at ON mission_series (next_occurrence_at)
+WHERE cancelled_at IS NULL;
+
+SELECT diesel_manage_updated_at('mission_series');
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX idx_missions_deleted_at ON missions (deleted_at) WHERE deleted_at IS NOT NULL;
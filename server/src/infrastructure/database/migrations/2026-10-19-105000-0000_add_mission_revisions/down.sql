@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_revisions;
+
+ALTER TABLE missions
+DROP COLUMN "version";
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN "version" INTEGER NOT NULL DEFAULT 1;
+
+-- Each row keeps the name and description a mission had at `version`,
+-- written by the edit that moved it to `version + 1`.
+CREATE TABLE mission_revisions (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    "version" INTEGER NOT NULL,
+    "name" VARCHAR(255) NOT NULL,
+    "description" TEXT,
+    edited_by INTEGER NOT NULL,
+    edited_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT unique_mission_revision_version UNIQUE (mission_id, "version")
+);
+
+ALTER TABLE
+    mission_revisions
+ADD
+    CONSTRAINT fk_mission_revision_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_revision_edited_by FOREIGN KEY (edited_by) REFERENCES brawlers(id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_outcome_votes;
+
+ALTER TABLE missions
+DROP COLUMN outcome_overridden_at,
+DROP COLUMN consensus_outcome;
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN consensus_outcome BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN outcome_overridden_at TIMESTAMP;
+
+CREATE TABLE mission_outcome_votes (
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    outcome VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, brawler_id),
+    CONSTRAINT check_outcome_vote CHECK (outcome IN ('Completed', 'Failed'))
+);
+
+ALTER TABLE
+    mission_outcome_votes
+ADD
+    CONSTRAINT fk_outcome_vote_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_outcome_vote_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+SELECT diesel_manage_updated_at('mission_outcome_votes');
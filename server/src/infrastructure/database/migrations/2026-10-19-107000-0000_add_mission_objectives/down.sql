@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_objectives;
+
+ALTER TABLE missions
+DROP COLUMN require_objectives;
@@ -0,0 +1,27 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN require_objectives BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE mission_objectives (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    "position" INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    "required" BOOLEAN NOT NULL DEFAULT TRUE,
+    completed_by INTEGER,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_objective_completion CHECK ((completed_by IS NULL) = (completed_at IS NULL))
+);
+
+ALTER TABLE
+    mission_objectives
+ADD
+    CONSTRAINT fk_objective_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_objective_completed_by FOREIGN KEY (completed_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_objectives_mission_id ON mission_objectives (mission_id, "position");
+
+SELECT diesel_manage_updated_at('mission_objectives');
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE crew_memberships
+DROP COLUMN "role";
+
+DROP TABLE IF EXISTS mission_role_slots;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE mission_role_slots (
+    mission_id INTEGER NOT NULL,
+    "role" VARCHAR(50) NOT NULL,
+    capacity INTEGER NOT NULL,
+    PRIMARY KEY (mission_id, "role"),
+    CONSTRAINT check_role_slot_capacity CHECK (capacity > 0)
+);
+
+ALTER TABLE
+    mission_role_slots
+ADD
+    CONSTRAINT fk_role_slot_mission FOREIGN KEY (mission_id) REFERENCES missions(id);
+
+-- Crew who joined before the mission had role slots keep a NULL role.
+ALTER TABLE crew_memberships
+ADD COLUMN "role" VARCHAR(50);
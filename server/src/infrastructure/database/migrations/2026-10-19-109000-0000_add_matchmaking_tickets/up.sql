@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE matchmaking_tickets (
+    brawler_id INTEGER PRIMARY KEY,
+    category VARCHAR(50),
+    "role" VARCHAR(50),
+    min_reputation FLOAT8,
+    status VARCHAR(50) NOT NULL DEFAULT 'Queued',
+    mission_id INTEGER,
+    proposed_at TIMESTAMP,
+    declined_mission_ids INTEGER[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT check_matchmaking_status CHECK (status IN ('Queued', 'Proposed'))
+);
+
+ALTER TABLE
+    matchmaking_tickets
+ADD
+    CONSTRAINT fk_matchmaking_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_matchmaking_mission FOREIGN KEY (mission_id) REFERENCES missions(id);
+
+CREATE INDEX idx_matchmaking_tickets_status ON matchmaking_tickets (status, created_at);
+
+SELECT diesel_manage_updated_at('matchmaking_tickets');
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS mission_attachments;
+
+ALTER TABLE missions
+DROP COLUMN banner_url,
+DROP COLUMN banner_public_id;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN banner_url TEXT,
+ADD COLUMN banner_public_id TEXT;
+
+CREATE TABLE mission_attachments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    uploaded_by INTEGER NOT NULL,
+    file_name VARCHAR(255),
+    mime_type VARCHAR(100) NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    public_id TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    mission_attachments
+ADD
+    CONSTRAINT fk_attachment_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_attachment_brawler FOREIGN KEY (uploaded_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_attachments_mission_id ON mission_attachments (mission_id);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE brawler_avatar_variants (
+    brawler_id INTEGER NOT NULL,
+    size INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    public_id TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (brawler_id, size)
+);
+
+ALTER TABLE
+    brawler_avatar_variants
+ADD
+    CONSTRAINT fk_avatar_variant_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+    DROP COLUMN banned_at,
+    DROP COLUMN is_admin;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+    ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN banned_at TIMESTAMP;
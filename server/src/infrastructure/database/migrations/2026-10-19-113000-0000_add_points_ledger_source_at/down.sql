@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE points_ledger
+    DROP CONSTRAINT unique_points_ledger_event,
+    ADD CONSTRAINT unique_points_ledger_event UNIQUE (brawler_id, "source", source_id);
+
+ALTER TABLE points_ledger
+    DROP COLUMN source_at;
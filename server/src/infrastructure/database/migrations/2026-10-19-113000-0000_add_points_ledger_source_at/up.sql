@@ -0,0 +1,14 @@
+-- Your SQL goes here
+-- Mission rows are awarded once per run of the mission, told apart by when it finished.
+ALTER TABLE points_ledger
+    ADD COLUMN source_at TIMESTAMP;
+
+UPDATE points_ledger
+SET source_at = missions.finished_at
+FROM missions
+WHERE points_ledger.source_id = missions.id
+    AND points_ledger."source" IN ('mission_completed', 'mission_failed', 'outcome_reversal');
+
+ALTER TABLE points_ledger
+    DROP CONSTRAINT unique_points_ledger_event,
+    ADD CONSTRAINT unique_points_ledger_event UNIQUE (brawler_id, "source", source_id, source_at);
@@ -1,3 +1,4 @@
+pub mod migration_runner;
 pub mod postgresql_connection;
 pub mod repositories;
 pub mod schema;
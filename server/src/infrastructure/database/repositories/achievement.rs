@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+
+use crate::{
+    domain::{
+        entities::{badges::BadgeEntity, brawler_badges::BrawlerBadgeEntity},
+        repositories::achievement::AchievementRepository,
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{badges, brawler_badges},
+    },
+};
+
+pub struct AchievementPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AchievementPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for AchievementPostgres {
+    async fn evaluate(&self, brawler_ids: Vec<i32>) -> Result<Vec<BrawlerBadgeEntity>> {
+        // Every badge definition is checked against every candidate in one
+        // statement; already awarded badges are skipped by the primary key.
+        // A new metric needs a branch here and in the `check_badge_metric` constraint.
+        let sql = r#"
+            WITH candidates AS (
+                SELECT DISTINCT unnest($1::INT4[]) AS brawler_id
+            ),
+            participations AS (
+                SELECT cm.brawler_id, m.status, m.finished_at, FALSE AS is_chief
+                FROM crew_memberships cm
+                INNER JOIN missions m ON m.id = cm.mission_id
+                WHERE m.deleted_at IS NULL
+                    AND cm.brawler_id = ANY($1)
+                UNION ALL
+                SELECT m.chief_id AS brawler_id, m.status, m.finished_at, TRUE AS is_chief
+                FROM missions m
+                WHERE m.deleted_at IS NULL
+                    AND m.chief_id = ANY($1)
+            ),
+            progress AS (
+                SELECT c.brawler_id,
+                        bd.id AS badge_id,
+                        bd.threshold,
+                        CASE bd.metric
+                            WHEN 'missions_completed' THEN (
+                                SELECT COUNT(*)
+                                FROM participations p
+                                WHERE p.brawler_id = c.brawler_id
+                                    AND p.status = $2
+                                    AND (bd.window_days IS NULL
+                                        OR p.finished_at >= now() - make_interval(days => bd.window_days))
+                            )
+                            WHEN 'missions_failed' THEN (
+                                SELECT COUNT(*)
+                                FROM participations p
+                                WHERE p.brawler_id = c.brawler_id
+                                    AND p.status = $3
+                                    AND (bd.window_days IS NULL
+                                        OR p.finished_at >= now() - make_interval(days => bd.window_days))
+                            )
+                            WHEN 'missions_led' THEN (
+                                SELECT COUNT(*)
+                                FROM participations p
+                                WHERE p.brawler_id = c.brawler_id
+                                    AND p.is_chief
+                                    AND p.status IN ($2, $3)
+                                    AND (bd.window_days IS NULL
+                                        OR p.finished_at >= now() - make_interval(days => bd.window_days))
+                            )
+                            WHEN 'missions_joined' THEN (
+                                SELECT COUNT(*)
+                                FROM crew_memberships cm
+                                WHERE cm.brawler_id = c.brawler_id
+                                    AND (bd.window_days IS NULL
+                                        OR cm.joined_at >= now() - make_interval(days => bd.window_days))
+                            )
+                        END AS current_value
+                FROM candidates c
+                CROSS JOIN badges bd
+            )
+            INSERT INTO brawler_badges (brawler_id, badge_id)
+            SELECT p.brawler_id, p.badge_id
+            FROM progress p
+            WHERE p.current_value >= p.threshold
+            ON CONFLICT DO NOTHING
+            RETURNING brawler_id, badge_id, awarded_at
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Array<diesel::sql_types::Int4>, _>(brawler_ids)
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Completed.to_string())
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Failed.to_string())
+            .load::<BrawlerBadgeEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_definitions(&self) -> Result<Vec<BadgeEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = badges::table
+            .select(BadgeEntity::as_select())
+            .order_by(badges::id.asc())
+            .load::<BadgeEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<(BrawlerBadgeEntity, BadgeEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = brawler_badges::table
+            .inner_join(badges::table)
+            .filter(brawler_badges::brawler_id.eq(brawler_id))
+            .select((BrawlerBadgeEntity::as_select(), BadgeEntity::as_select()))
+            .order_by(brawler_badges::awarded_at.desc())
+            .load::<(BrawlerBadgeEntity, BadgeEntity)>(&mut conn)?;
+        Ok(result)
+    }
+}
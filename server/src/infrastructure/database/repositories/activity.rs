@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{RunQueryDsl, insert_into};
+
+use crate::{
+    domain::{
+        entities::activity_events::AddActivityEventEntity,
+        repositories::activity::ActivityRepository,
+        value_objects::activity_model::ActivityEventModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::activity_events},
+};
+
+pub struct ActivityPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ActivityPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ActivityRepository for ActivityPostgres {
+    async fn record(&self, add_activity_event_entity: AddActivityEventEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_into(activity_events::table)
+            .values(add_activity_event_entity)
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_feed(
+        &self,
+        viewer_id: Option<i32>,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<ActivityEventModel>> {
+        let sql = r#"
+            SELECT e.id,
+                    e.kind,
+                    e.actor_id,
+                    b.display_name AS actor_display_name,
+                    e.mission_id,
+                    m.name AS mission_name,
+                    e.created_at
+            FROM activity_events e
+            INNER JOIN brawlers b ON b.id = e.actor_id
+            INNER JOIN missions m ON m.id = e.mission_id
+            WHERE m.deleted_at IS NULL
+                AND ($1::INT4 IS NULL OR e.id < $1)
+                AND (
+                    $2::INT4 IS NULL
+                    OR e.actor_id = $2
+                    OR m.chief_id = $2
+                    OR e.actor_id IN (
+                        SELECT f.followee_id
+                        FROM brawler_follows f
+                        WHERE f.follower_id = $2
+                    )
+                    OR e.mission_id IN (
+                        SELECT cm.mission_id
+                        FROM crew_memberships cm
+                        WHERE cm.brawler_id = $2
+                    )
+                )
+            ORDER BY e.id DESC
+            LIMIT $3
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Int4>, _>(before_id)
+            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Int4>, _>(viewer_id)
+            .bind::<diesel::sql_types::BigInt, _>(limit)
+            .load::<ActivityEventModel>(&mut conn)?;
+        Ok(result)
+    }
+}
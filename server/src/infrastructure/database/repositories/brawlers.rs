@@ -1,22 +1,29 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDateTime, Utc};
 use diesel::{
-    ExpressionMethods, RunQueryDsl, SelectableHelper, insert_into,
-    query_dsl::methods::{FilterDsl, SelectDsl},
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    insert_into,
 };
 use std::sync::Arc;
 
 use crate::{
     config::config_loader::get_jwt_env,
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        entities::{
+            brawler_avatar_variants::{AddBrawlerAvatarVariantEntity, BrawlerAvatarVariantEntity},
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        },
         repositories::brawlers::BrawlerRepository,
-        value_objects::{base64_img::Base64Img, uploaded_img::UploadedImg},
+        value_objects::brawler_model::{
+            BrawlerProfileModel, avatar_or_default, default_avatar_url,
+        },
     },
     infrastructure::{
-        cloudinary::{self, UploadImageOptions},
-        database::{postgresql_connection::PgPoolSquad, schema::brawlers},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            schema::{brawler_avatar_variants, brawlers},
+        },
         jwt::{
             generate_token,
             jwt_model::{Claims, Passport},
@@ -56,7 +63,7 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(Passport {
             token,
             display_name,
-            avatar_url: None,
+            avatar_url: Some(default_avatar_url(user_id)),
         })
     }
 
@@ -71,24 +78,178 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn upload_base64img(
+    async fn find_by_id(&self, brawler_id: i32) -> Result<Option<BrawlerEntity>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_profile(&self, brawler_id: i32) -> Result<BrawlerProfileModel> {
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    b.created_at,
+                    COALESCE(AVG(mr.score), 0)::FLOAT8 AS reputation_score,
+                    COUNT(mr.id) AS rating_count
+            FROM brawlers b
+            LEFT JOIN mission_ratings mr ON mr.ratee_id = b.id
+            WHERE b.id = $1
+            GROUP BY b.id
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mut result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<BrawlerProfileModel>(&mut conn)?;
+        result.avatar_url = avatar_or_default(result.avatar_url, result.brawler_id);
+
+        Ok(result)
+    }
+
+    async fn update_avatar(
         &self,
         user_id: i32,
-        base64img: Base64Img,
-        opt: UploadImageOptions,
-    ) -> Result<UploadedImg> {
-        let uploaded_img = cloudinary::upload(base64img, opt).await?;
+        variants: Vec<AddBrawlerAvatarVariantEntity>,
+    ) -> Result<Vec<String>> {
+        let avatar = variants
+            .iter()
+            .max_by_key(|variant| variant.size)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("avatar has no variants"))?;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let previous = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let mut previous = brawlers::table
+                .filter(brawlers::id.eq(user_id))
+                .select(brawlers::avatar_public_id)
+                .for_update()
+                .first::<Option<String>>(conn)?
+                .into_iter()
+                .collect::<Vec<String>>();
+            previous.extend(
+                diesel::delete(brawler_avatar_variants::table)
+                    .filter(brawler_avatar_variants::brawler_id.eq(user_id))
+                    .returning(brawler_avatar_variants::public_id)
+                    .get_results::<String>(conn)?,
+            );
+
+            insert_into(brawler_avatar_variants::table)
+                .values(&variants)
+                .execute(conn)?;
+
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(user_id))
+                .set((
+                    brawlers::avatar_url.eq(avatar.url),
+                    brawlers::avatar_public_id.eq(avatar.public_id),
+                ))
+                .execute(conn)?;
+
+            Ok(previous)
+        })?;
+
+        Ok(previous)
+    }
+
+    async fn remove_avatar(&self, user_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let public_ids = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let mut public_ids = brawlers::table
+                .filter(brawlers::id.eq(user_id))
+                .select(brawlers::avatar_public_id)
+                .for_update()
+                .first::<Option<String>>(conn)?
+                .into_iter()
+                .collect::<Vec<String>>();
+            public_ids.extend(
+                diesel::delete(brawler_avatar_variants::table)
+                    .filter(brawler_avatar_variants::brawler_id.eq(user_id))
+                    .returning(brawler_avatar_variants::public_id)
+                    .get_results::<String>(conn)?,
+            );
+            if public_ids.is_empty() {
+                return Err(anyhow::anyhow!("You have no avatar to remove"));
+            }
+
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(user_id))
+                .set((
+                    brawlers::avatar_url.eq(None::<String>),
+                    brawlers::avatar_public_id.eq(None::<String>),
+                ))
+                .execute(conn)?;
+
+            Ok(public_ids)
+        })?;
+
+        Ok(public_ids)
+    }
+
+    async fn get_avatar_variants(
+        &self,
+        brawler_id: i32,
+    ) -> Result<Vec<BrawlerAvatarVariantEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawler_avatar_variants::table
+            .filter(brawler_avatar_variants::brawler_id.eq(brawler_id))
+            .order_by(brawler_avatar_variants::size.desc())
+            .select(BrawlerAvatarVariantEntity::as_select())
+            .load::<BrawlerAvatarVariantEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_ids(&self) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .order_by(brawlers::id)
+            .select(brawlers::id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn update_password(&self, brawler_id: i32, password: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::password.eq(password))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn register_admin(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let brawler_id = insert_into(brawlers::table)
+            .values((&register_brawler_entity, brawlers::is_admin.eq(true)))
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(brawler_id)
+    }
 
+    async fn set_banned_at(&self, brawler_id: i32, banned_at: Option<NaiveDateTime>) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         diesel::update(brawlers::table)
-            .filter(brawlers::id.eq(user_id))
-            .set((
-                brawlers::avatar_url.eq(uploaded_img.url.clone()),
-                brawlers::avatar_public_id.eq(uploaded_img.public_id.clone()),
-            ))
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::banned_at.eq(banned_at))
             .execute(&mut conn)?;
 
-        Ok(uploaded_img)
+        Ok(())
     }
 }
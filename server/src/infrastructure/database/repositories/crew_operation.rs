@@ -1,14 +1,24 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, update},
+    insert_into,
+};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMemberShips,
+        entities::{
+            crew_memberships::CrewMemberShips, mission_invitations::MissionInvitationEntity,
+            missions::MissionEntity,
+        },
         repositories::crew_operation::CrewOperationRepository,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{crew_memberships, mission_invitations, missions},
+    },
 };
 
 pub struct CrewOperationPostgres {
@@ -39,4 +49,44 @@ impl CrewOperationRepository for CrewOperationPostgres {
             .execute(&mut conn)?;
         Ok(())
     }
+
+    async fn change_role(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let updated = update(crew_memberships::table)
+            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+            .set(crew_memberships::role.eq(crew_member_ships.role))
+            .execute(&mut conn)?;
+        if updated == 0 {
+            return Err(anyhow::anyhow!("You are not in the crew of this mission"));
+        }
+        Ok(())
+    }
+
+    async fn get_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> Result<Vec<(MissionInvitationEntity, MissionEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_invitations::table
+            .inner_join(missions::table)
+            .filter(mission_invitations::brawler_id.eq(brawler_id))
+            .filter(missions::deleted_at.is_null())
+            .select((
+                MissionInvitationEntity::as_select(),
+                MissionEntity::as_select(),
+            ))
+            .order_by(mission_invitations::created_at.desc())
+            .load::<(MissionInvitationEntity, MissionEntity)>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn remove_invitation(&self, mission_id: i32, brawler_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = delete(mission_invitations::table)
+            .filter(mission_invitations::mission_id.eq(mission_id))
+            .filter(mission_invitations::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+        Ok(result)
+    }
 }
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, update},
+    insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            guild_invitations::{AddGuildInvitationEntity, GuildInvitationEntity},
+            guild_memberships::GuildMembershipEntity,
+            guilds::{AddGuildEntity, GuildEntity},
+        },
+        repositories::guild::GuildRepository,
+        value_objects::{
+            brawler_model::avatar_or_default,
+            guild_model::{GuildMemberModel, GuildStatsModel},
+            guild_roles::GuildRoles,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{guild_invitations, guild_memberships, guilds},
+    },
+};
+
+pub struct GuildPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl GuildPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl GuildRepository for GuildPostgres {
+    async fn create(&self, add_guild_entity: AddGuildEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let guild_id = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let owner_id = add_guild_entity.owner_id;
+            let guild_id = insert_into(guilds::table)
+                .values(add_guild_entity)
+                .returning(guilds::id)
+                .get_result::<i32>(conn)?;
+
+            insert_into(guild_memberships::table)
+                .values(GuildMembershipEntity {
+                    guild_id,
+                    brawler_id: owner_id,
+                    role: GuildRoles::Owner.to_string(),
+                })
+                .execute(conn)?;
+
+            Ok(guild_id)
+        })?;
+
+        Ok(guild_id)
+    }
+
+    async fn get_one(&self, guild_id: i32) -> Result<GuildEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = guilds::table
+            .filter(guilds::id.eq(guild_id))
+            .select(GuildEntity::as_select())
+            .first::<GuildEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_members(&self, guild_id: i32) -> Result<Vec<GuildMemberModel>> {
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    gm.role,
+                    gm.joined_at
+            FROM guild_memberships gm
+            INNER JOIN brawlers b ON b.id = gm.brawler_id
+            WHERE gm.guild_id = $1
+            ORDER BY gm.joined_at
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(guild_id)
+            .load::<GuildMemberModel>(&mut conn)?
+            .into_iter()
+            .map(|mut member| {
+                member.avatar_url = avatar_or_default(member.avatar_url, member.brawler_id);
+                member
+            })
+            .collect();
+        Ok(result)
+    }
+
+    async fn get_role(&self, guild_id: i32, brawler_id: i32) -> Result<Option<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = guild_memberships::table
+            .filter(guild_memberships::guild_id.eq(guild_id))
+            .filter(guild_memberships::brawler_id.eq(brawler_id))
+            .select(guild_memberships::role)
+            .first::<String>(&mut conn)
+            .optional()?;
+        Ok(result)
+    }
+
+    async fn get_stats(&self, guild_id: i32) -> Result<GuildStatsModel> {
+        let sql = r#"
+            SELECT g.id AS guild_id,
+                    (
+                        SELECT COUNT(*)
+                        FROM guild_memberships gm
+                        WHERE gm.guild_id = g.id
+                    ) AS member_count,
+                    COUNT(m.id) FILTER (WHERE m.status = $2) AS open_mission_count,
+                    COUNT(m.id) FILTER (WHERE m.status = $3) AS in_progress_mission_count,
+                    COUNT(m.id) FILTER (WHERE m.status = $4) AS completed_mission_count,
+                    COUNT(m.id) FILTER (WHERE m.status = $5) AS failed_mission_count,
+                    (
+                        SELECT COALESCE(SUM(s.completed_count), 0)::INT8
+                        FROM brawler_leaderboard_stats s
+                        INNER JOIN guild_memberships gm ON gm.brawler_id = s.brawler_id
+                        WHERE gm.guild_id = g.id
+                            AND s.season_id = 0
+                    ) AS member_completed_count,
+                    (
+                        SELECT COALESCE(SUM(s.failed_count), 0)::INT8
+                        FROM brawler_leaderboard_stats s
+                        INNER JOIN guild_memberships gm ON gm.brawler_id = s.brawler_id
+                        WHERE gm.guild_id = g.id
+                            AND s.season_id = 0
+                    ) AS member_failed_count
+            FROM guilds g
+            LEFT JOIN missions m ON m.guild_id = g.id AND m.deleted_at IS NULL
+            WHERE g.id = $1
+            GROUP BY g.id
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(guild_id)
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Open.to_string())
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::InProgress.to_string())
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Completed.to_string())
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Failed.to_string())
+            .get_result::<GuildStatsModel>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn invite(&self, add_guild_invitation_entity: AddGuildInvitationEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_into(guild_invitations::table)
+            .values(add_guild_invitation_entity)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> Result<Vec<(GuildInvitationEntity, GuildEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = guild_invitations::table
+            .inner_join(guilds::table)
+            .filter(guild_invitations::brawler_id.eq(brawler_id))
+            .select((GuildInvitationEntity::as_select(), GuildEntity::as_select()))
+            .order_by(guild_invitations::created_at.desc())
+            .load::<(GuildInvitationEntity, GuildEntity)>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn accept_invitation(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let removed = delete(guild_invitations::table)
+                .filter(guild_invitations::guild_id.eq(guild_id))
+                .filter(guild_invitations::brawler_id.eq(brawler_id))
+                .execute(conn)?;
+            if removed == 0 {
+                return Err(anyhow::anyhow!("Invitation not found"));
+            }
+
+            insert_into(guild_memberships::table)
+                .values(GuildMembershipEntity {
+                    guild_id,
+                    brawler_id,
+                    role: GuildRoles::Member.to_string(),
+                })
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn decline_invitation(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(guild_invitations::table)
+            .filter(guild_invitations::guild_id.eq(guild_id))
+            .filter(guild_invitations::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn update_role(&self, guild_id: i32, brawler_id: i32, role: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        update(guild_memberships::table)
+            .filter(guild_memberships::guild_id.eq(guild_id))
+            .filter(guild_memberships::brawler_id.eq(brawler_id))
+            .set(guild_memberships::role.eq(role))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn remove_member(&self, guild_id: i32, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(guild_memberships::table)
+            .filter(guild_memberships::guild_id.eq(guild_id))
+            .filter(guild_memberships::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+}
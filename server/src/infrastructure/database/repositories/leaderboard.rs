@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, PgConnection, QueryDsl, QueryResult, RunQueryDsl, SelectableHelper,
+};
+
+use crate::{
+    domain::{
+        entities::seasons::SeasonEntity,
+        repositories::leaderboard::LeaderboardRepository,
+        value_objects::{
+            brawler_model::avatar_or_default,
+            leaderboard_model::{LeaderboardEntryModel, LeaderboardMetric},
+        },
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::seasons},
+};
+
+const SUCCESS_RATE_SQL: &str =
+    "COALESCE(s.completed_count::FLOAT8 / NULLIF(s.completed_count + s.failed_count, 0), 0)";
+
+pub fn refresh_stats(conn: &mut PgConnection) -> QueryResult<usize> {
+    diesel::sql_query("REFRESH MATERIALIZED VIEW CONCURRENTLY brawler_leaderboard_stats")
+        .execute(conn)
+}
+
+pub struct LeaderboardPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LeaderboardPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LeaderboardRepository for LeaderboardPostgres {
+    async fn get_leaderboard(
+        &self,
+        metric: LeaderboardMetric,
+        season_id: i32,
+        min_sample: i32,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntryModel>> {
+        let (condition, ordering) = match metric {
+            LeaderboardMetric::Completed => (
+                "s.completed_count > 0",
+                "s.completed_count DESC".to_string(),
+            ),
+            LeaderboardMetric::SuccessRate => (
+                "s.completed_count + s.failed_count >= $2",
+                format!("{SUCCESS_RATE_SQL} DESC, s.completed_count DESC"),
+            ),
+            LeaderboardMetric::Led => ("s.led_count > 0", "s.led_count DESC".to_string()),
+        };
+
+        let sql = format!(
+            r#"
+            SELECT RANK() OVER (ORDER BY {ordering}) AS rank,
+                    s.brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    s.completed_count,
+                    s.failed_count,
+                    s.led_count,
+                    {SUCCESS_RATE_SQL} AS success_rate
+            FROM brawler_leaderboard_stats s
+            INNER JOIN brawlers b ON b.id = s.brawler_id
+            WHERE s.season_id = $1
+                AND {condition}
+            ORDER BY rank, s.brawler_id
+            LIMIT $3
+        "#
+        );
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(season_id)
+            .bind::<diesel::sql_types::Int4, _>(min_sample)
+            .bind::<diesel::sql_types::Int8, _>(limit)
+            .load::<LeaderboardEntryModel>(&mut conn)?
+            .into_iter()
+            .map(|mut entry| {
+                entry.avatar_url = avatar_or_default(entry.avatar_url, entry.brawler_id);
+                entry
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    async fn get_seasons(&self) -> Result<Vec<SeasonEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = seasons::table
+            .select(SeasonEntity::as_select())
+            .order_by(seasons::starts_at.desc())
+            .load::<SeasonEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn refresh(&self) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        refresh_stats(&mut conn)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, now, sql, update},
+    insert_into,
+    sql_types::{Array, Integer},
+};
+
+use crate::{
+    domain::{
+        entities::{
+            matchmaking_tickets::{AddMatchmakingTicketEntity, MatchmakingTicketEntity},
+            missions::MissionEntity,
+        },
+        repositories::matchmaking::MatchmakingRepository,
+        value_objects::{
+            matchmaking_model::MatchmakingStatuses, mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{matchmaking_tickets, missions},
+    },
+};
+
+pub struct MatchmakingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MatchmakingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MatchmakingRepository for MatchmakingPostgres {
+    async fn enqueue(
+        &self,
+        add_matchmaking_ticket_entity: AddMatchmakingTicketEntity,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let inserted = insert_into(matchmaking_tickets::table)
+            .values(add_matchmaking_ticket_entity)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+        if inserted == 0 {
+            return Err(anyhow::anyhow!("You are already in the matchmaking queue"));
+        }
+        Ok(())
+    }
+
+    async fn get_ticket(&self, brawler_id: i32) -> Result<Option<MatchmakingTicketEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = matchmaking_tickets::table
+            .filter(matchmaking_tickets::brawler_id.eq(brawler_id))
+            .select(MatchmakingTicketEntity::as_select())
+            .first::<MatchmakingTicketEntity>(&mut conn)
+            .optional()?;
+        Ok(result)
+    }
+
+    async fn remove(&self, brawler_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let removed = delete(matchmaking_tickets::table)
+            .filter(matchmaking_tickets::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+        Ok(removed)
+    }
+
+    async fn get_by_status(&self, status: &str) -> Result<Vec<MatchmakingTicketEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = matchmaking_tickets::table
+            .filter(matchmaking_tickets::status.eq(status))
+            .order_by(matchmaking_tickets::created_at.asc())
+            .select(MatchmakingTicketEntity::as_select())
+            .load::<MatchmakingTicketEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_open_missions(&self) -> Result<Vec<MissionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = missions::table
+            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::guild_only.eq(false))
+            .order_by(missions::created_at.asc())
+            .select(MissionEntity::as_select())
+            .load::<MissionEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn propose(&self, brawler_id: i32, mission_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let proposed = update(matchmaking_tickets::table)
+            .filter(matchmaking_tickets::brawler_id.eq(brawler_id))
+            .filter(matchmaking_tickets::status.eq(MatchmakingStatuses::Queued.to_string()))
+            .set((
+                matchmaking_tickets::status.eq(MatchmakingStatuses::Proposed.to_string()),
+                matchmaking_tickets::mission_id.eq(mission_id),
+                matchmaking_tickets::proposed_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+        Ok(proposed > 0)
+    }
+
+    async fn requeue(&self, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let requeued = update(matchmaking_tickets::table)
+            .filter(matchmaking_tickets::brawler_id.eq(brawler_id))
+            .filter(matchmaking_tickets::status.eq(MatchmakingStatuses::Proposed.to_string()))
+            .set((
+                matchmaking_tickets::status.eq(MatchmakingStatuses::Queued.to_string()),
+                matchmaking_tickets::mission_id.eq(None::<i32>),
+                matchmaking_tickets::proposed_at.eq(None::<NaiveDateTime>),
+                matchmaking_tickets::declined_mission_ids.eq(sql::<Array<Integer>>(
+                    "array_append(declined_mission_ids, mission_id)",
+                )),
+            ))
+            .execute(&mut conn)?;
+        if requeued == 0 {
+            return Err(anyhow::anyhow!("No pending matchmaking proposal"));
+        }
+        Ok(())
+    }
+}
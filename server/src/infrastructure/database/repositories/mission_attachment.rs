@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{count_star, delete, update},
+    insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::mission_attachments::{AddMissionAttachmentEntity, MissionAttachmentEntity},
+        repositories::mission_attachment::MissionAttachmentRepository,
+        value_objects::uploaded_img::UploadedImg,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_attachments, missions},
+    },
+};
+
+pub struct MissionAttachmentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionAttachmentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionAttachmentRepository for MissionAttachmentPostgres {
+    async fn set_banner(
+        &self,
+        mission_id: i32,
+        uploaded_img: UploadedImg,
+    ) -> Result<Option<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let previous = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let previous = missions::table
+                .filter(missions::id.eq(mission_id))
+                .select(missions::banner_public_id)
+                .for_update()
+                .first::<Option<String>>(conn)?;
+
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    missions::banner_url.eq(uploaded_img.url),
+                    missions::banner_public_id.eq(uploaded_img.public_id),
+                ))
+                .execute(conn)?;
+
+            Ok(previous)
+        })?;
+
+        Ok(previous)
+    }
+
+    async fn remove_banner(&self, mission_id: i32) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let public_id = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let public_id = missions::table
+                .filter(missions::id.eq(mission_id))
+                .select(missions::banner_public_id)
+                .for_update()
+                .first::<Option<String>>(conn)?
+                .ok_or_else(|| anyhow::anyhow!("Mission has no banner"))?;
+
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    missions::banner_url.eq(None::<String>),
+                    missions::banner_public_id.eq(None::<String>),
+                ))
+                .execute(conn)?;
+
+            Ok(public_id)
+        })?;
+
+        Ok(public_id)
+    }
+
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionAttachmentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_attachments::table
+            .filter(mission_attachments::mission_id.eq(mission_id))
+            .order_by(mission_attachments::created_at.asc())
+            .select(MissionAttachmentEntity::as_select())
+            .load::<MissionAttachmentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_one(&self, attachment_id: i32) -> Result<MissionAttachmentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_attachments::table
+            .filter(mission_attachments::id.eq(attachment_id))
+            .select(MissionAttachmentEntity::as_select())
+            .first::<MissionAttachmentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn count(&self, mission_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_attachments::table
+            .filter(mission_attachments::mission_id.eq(mission_id))
+            .select(count_star())
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn add(&self, add_mission_attachment_entity: AddMissionAttachmentEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(mission_attachments::table)
+            .values(add_mission_attachment_entity)
+            .returning(mission_attachments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, attachment_id: i32) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = delete(mission_attachments::table)
+            .filter(mission_attachments::id.eq(attachment_id))
+            .returning(mission_attachments::public_id)
+            .get_result::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+}
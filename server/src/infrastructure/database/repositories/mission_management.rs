@@ -1,14 +1,34 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
+        entities::{
+            mission_invitations::AddMissionInvitationEntity,
+            mission_revisions::AddMissionRevisionEntity,
+            mission_role_slots::MissionRoleSlotEntity,
+            missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+        },
         repositories::mission_management::MissionManagementRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        value_objects::{
+            matchmaking_model::MatchmakingStatuses, mission_revision_model::VersionConflict,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{
+            activity_events, crew_memberships, matchmaking_tickets, mission_attachments,
+            mission_invitations, mission_objectives, mission_outcome_votes, mission_ratings,
+            mission_revisions, mission_role_slots, missions,
+        },
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, now, update},
+    insert_into,
+};
 use std::sync::Arc;
 
 pub struct MissionManagementPostgres {
@@ -32,31 +52,211 @@ impl MissionManagementRepository for MissionManagementPostgres {
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        expected_version: Option<i32>,
+        edit_mission_entity: EditMissionEntity,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let mission = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+                .select(MissionEntity::as_select())
+                .for_update()
+                .first::<MissionEntity>(conn)
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Mission not found"))?;
+
+            if expected_version.is_some_and(|version| version != mission.version) {
+                return Err(VersionConflict {
+                    current_version: mission.version,
+                }
+                .into());
+            }
+
+            insert_into(mission_revisions::table)
+                .values(AddMissionRevisionEntity {
+                    mission_id,
+                    version: mission.version,
+                    name: mission.name,
+                    description: mission.description,
+                    edited_by: chief_id,
+                })
+                .execute(conn)?;
+
+            let result = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    edit_mission_entity,
+                    missions::version.eq(missions::version + 1),
+                    missions::updated_at.eq(now),
+                ))
+                .returning(missions::version)
+                .get_result::<i32>(conn)?;
+
+            Ok(result)
+        })?;
+
         Ok(result)
     }
 
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        update(missions::table)
+        let removed = update(missions::table)
             .filter(missions::id.eq(mission_id))
+            .filter(missions::chief_id.eq(chief_id))
             .filter(missions::deleted_at.is_null())
             .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+            .set((missions::deleted_at.eq(now), missions::updated_at.eq(now)))
+            .execute(&mut conn)?;
+
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Mission not found"));
+        }
+
+        Ok(())
+    }
+
+    async fn get_deleted(&self, chief_id: i32, since: NaiveDateTime) -> Result<Vec<MissionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = missions::table
+            .filter(missions::chief_id.eq(chief_id))
+            .filter(missions::deleted_at.ge(since))
+            .order_by(missions::deleted_at.desc())
+            .select(MissionEntity::as_select())
+            .load::<MissionEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn restore(&self, mission_id: i32, chief_id: i32, since: NaiveDateTime) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::chief_id.eq(chief_id))
+            .filter(missions::deleted_at.ge(since))
             .set((
-                missions::deleted_at.eq(now),
-                missions::chief_id.eq(chief_id),
+                missions::deleted_at.eq(None::<NaiveDateTime>),
+                missions::updated_at.eq(now),
             ))
-            .execute(&mut conn)?;
+            .returning(missions::id)
+            .get_result::<i32>(&mut conn)
+            .optional()?;
+
+        result.ok_or_else(|| anyhow::anyhow!("Mission not found in trash"))
+    }
+
+    async fn purge_deleted(&self, before: NaiveDateTime) -> Result<(usize, Vec<String>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let mission_ids = missions::table
+                .filter(missions::deleted_at.lt(before))
+                .select(missions::id)
+                .for_update()
+                .load::<i32>(conn)?;
+            if mission_ids.is_empty() {
+                return Ok((0, Vec::new()));
+            }
+
+            let mut public_ids = mission_attachments::table
+                .filter(mission_attachments::mission_id.eq_any(&mission_ids))
+                .select(mission_attachments::public_id)
+                .load::<String>(conn)?;
+            public_ids.extend(
+                missions::table
+                    .filter(missions::id.eq_any(&mission_ids))
+                    .select(missions::banner_public_id)
+                    .load::<Option<String>>(conn)?
+                    .into_iter()
+                    .flatten(),
+            );
+
+            delete(mission_attachments::table)
+                .filter(mission_attachments::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            delete(activity_events::table)
+                .filter(activity_events::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_ratings::table)
+                .filter(mission_ratings::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_invitations::table)
+                .filter(mission_invitations::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_objectives::table)
+                .filter(mission_objectives::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_outcome_votes::table)
+                .filter(mission_outcome_votes::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_role_slots::table)
+                .filter(mission_role_slots::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_revisions::table)
+                .filter(mission_revisions::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            // Pending matchmaking proposals for a purged mission go back to the queue.
+            update(matchmaking_tickets::table)
+                .filter(matchmaking_tickets::mission_id.eq_any(&mission_ids))
+                .set((
+                    matchmaking_tickets::status.eq(MatchmakingStatuses::Queued.to_string()),
+                    matchmaking_tickets::mission_id.eq(None::<i32>),
+                    matchmaking_tickets::proposed_at.eq(None::<NaiveDateTime>),
+                ))
+                .execute(conn)?;
 
+            let purged = delete(missions::table)
+                .filter(missions::id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            Ok((purged, public_ids))
+        })?;
+
+        Ok(result)
+    }
+
+    async fn set_role_slots(
+        &self,
+        mission_id: i32,
+        slots: Vec<MissionRoleSlotEntity>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            delete(mission_role_slots::table)
+                .filter(mission_role_slots::mission_id.eq(mission_id))
+                .execute(conn)?;
+            if !slots.is_empty() {
+                insert_into(mission_role_slots::table)
+                    .values(&slots)
+                    .execute(conn)?;
+            }
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn invite_crew(&self, invitations: Vec<AddMissionInvitationEntity>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_into(mission_invitations::table)
+            .values(invitations)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
         Ok(())
     }
 }
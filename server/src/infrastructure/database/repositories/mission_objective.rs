@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{self, delete, now, update},
+    insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::mission_objectives::{
+            AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+        },
+        repositories::mission_objective::MissionObjectiveRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_objectives},
+};
+
+pub struct MissionObjectivePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionObjectivePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionObjectiveRepository for MissionObjectivePostgres {
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionObjectiveEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_objectives::table
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .order_by((
+                mission_objectives::position.asc(),
+                mission_objectives::id.asc(),
+            ))
+            .select(MissionObjectiveEntity::as_select())
+            .load::<MissionObjectiveEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, objective_id: i32) -> Result<MissionObjectiveEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_objectives::table
+            .filter(mission_objectives::id.eq(objective_id))
+            .select(MissionObjectiveEntity::as_select())
+            .first::<MissionObjectiveEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn add(
+        &self,
+        mut add_mission_objective_entity: AddMissionObjectiveEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<i32, anyhow::Error, _>(|conn| {
+            let last_position = mission_objectives::table
+                .filter(mission_objectives::mission_id.eq(add_mission_objective_entity.mission_id))
+                .select(dsl::max(mission_objectives::position))
+                .first::<Option<i32>>(conn)?;
+            add_mission_objective_entity.position = last_position.unwrap_or(0) + 1;
+
+            let objective_id = insert_into(mission_objectives::table)
+                .values(&add_mission_objective_entity)
+                .returning(mission_objectives::id)
+                .get_result::<i32>(conn)?;
+            Ok(objective_id)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        objective_id: i32,
+        edit_mission_objective_entity: EditMissionObjectiveEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = update(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .set((
+                edit_mission_objective_entity,
+                mission_objectives::updated_at.eq(now),
+            ))
+            .returning(mission_objectives::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn remove(&self, objective_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn reorder(&self, mission_id: i32, objective_ids: Vec<i32>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            for (position, objective_id) in (1..).zip(objective_ids) {
+                update(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .filter(mission_objectives::mission_id.eq(mission_id))
+                    .set((
+                        mission_objectives::position.eq(position),
+                        mission_objectives::updated_at.eq(now),
+                    ))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn set_completed(&self, objective_id: i32, completed_by: Option<i32>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let completed_at = completed_by.map(|_| Utc::now().naive_utc());
+        update(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .set((
+                mission_objectives::completed_by.eq(completed_by),
+                mission_objectives::completed_at.eq(completed_at),
+                mission_objectives::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+}
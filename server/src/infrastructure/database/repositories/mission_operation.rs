@@ -2,15 +2,36 @@ use std::sync::Arc;
 
 use anyhow::{Context, Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use chrono::{NaiveDateTime, Utc};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+    dsl::{delete, now, update},
+    insert_into,
+    upsert::excluded,
+};
+use tracing::warn;
 
 use crate::{
     domain::{
+        entities::mission_outcome_votes::{AddMissionOutcomeVoteEntity, MissionOutcomeVoteEntity},
         repositories::mission_operation::MissionOperationRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        value_objects::mission_statuses::{MissionStatuses, StatusConflict},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::leaderboard::refresh_stats,
+        schema::{mission_outcome_votes, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
+/// The status change has already been committed by then, so a failed refresh
+/// must not fail the request; the next refresh catches the stats up.
+fn refresh_stats_or_warn(conn: &mut PgConnection) {
+    if let Err(e) = refresh_stats(conn) {
+        warn!("Failed to refresh leaderboard stats: {}", e);
+    }
+}
+
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -24,21 +45,61 @@ impl MissionOperationPostgres {
         &self,
         mission_id: i32,
         chief_id: i32,
+        from: &[MissionStatuses],
         status: MissionStatuses,
     ) -> Result<i32> {
         let db_pool = Arc::clone(&self.db_pool);
+        let from = from.iter().map(|from| from.to_string()).collect::<Vec<_>>();
         let status_string = status.to_string();
+        let finished_at = match status {
+            MissionStatuses::Completed | MissionStatuses::Failed => Some(Utc::now().naive_utc()),
+            _ => None,
+        };
         let id = tokio::task::spawn_blocking(move || -> Result<i32> {
             let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
-            update(missions::table)
-                .filter(missions::id.eq(mission_id))
-                .filter(missions::chief_id.eq(chief_id))
-                .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_string),))
-                .returning(missions::id)
-                .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
+            let restarted = status == MissionStatuses::InProgress;
+            let was_finished = conn.transaction::<_, anyhow::Error, _>(|conn| {
+                let was_finished = missions::table
+                    .filter(missions::id.eq(mission_id))
+                    .select(missions::finished_at.is_not_null())
+                    .first::<bool>(conn)
+                    .context("Failed to read mission status")?;
+
+                update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .filter(missions::chief_id.eq(chief_id))
+                    .filter(missions::status.eq_any(from))
+                    .filter(missions::deleted_at.is_null())
+                    .set((
+                        missions::status.eq(status_string),
+                        missions::finished_at.eq(finished_at),
+                        missions::outcome_overridden_at.eq(None::<NaiveDateTime>),
+                        missions::updated_at.eq(now),
+                    ))
+                    .returning(missions::id)
+                    .get_result::<i32>(conn)
+                    .optional()
+                    .context("Failed to execute mission update query")?
+                    .ok_or(StatusConflict { mission_id })?;
+
+                // Every run is voted on afresh; the votes of a failed run must
+                // not decide the next one.
+                if restarted {
+                    delete(mission_outcome_votes::table)
+                        .filter(mission_outcome_votes::mission_id.eq(mission_id))
+                        .execute(conn)
+                        .context("Failed to clear outcome votes")?;
+                }
+                Ok(was_finished)
+            })?;
+
+            // Only finished missions count towards the stats, so nothing else
+            // needs the whole view rebuilt.
+            if was_finished || finished_at.is_some() {
+                refresh_stats_or_warn(&mut conn);
+            }
+            Ok(mission_id)
         })
         .await??;
 
@@ -50,7 +111,12 @@ impl MissionOperationPostgres {
 impl MissionOperationRepository for MissionOperationPostgres {
     async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::InProgress)
+            .set_status(
+                mission_id,
+                chief_id,
+                &[MissionStatuses::Open, MissionStatuses::Failed],
+                MissionStatuses::InProgress,
+            )
             .await?;
 
         Ok(result)
@@ -58,7 +124,12 @@ impl MissionOperationRepository for MissionOperationPostgres {
 
     async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Completed)
+            .set_status(
+                mission_id,
+                chief_id,
+                &[MissionStatuses::InProgress],
+                MissionStatuses::Completed,
+            )
             .await?;
 
         Ok(result)
@@ -66,9 +137,71 @@ impl MissionOperationRepository for MissionOperationPostgres {
 
     async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Failed)
+            .set_status(
+                mission_id,
+                chief_id,
+                &[MissionStatuses::InProgress],
+                MissionStatuses::Failed,
+            )
             .await?;
 
         Ok(result)
     }
+
+    async fn override_outcome(
+        &self,
+        mission_id: i32,
+        from: MissionStatuses,
+        to: MissionStatuses,
+    ) -> Result<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
+            let mut conn = db_pool.get().context("Failed to get DB connection")?;
+
+            let id = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::status.eq(from.to_string()))
+                .filter(missions::outcome_overridden_at.is_null())
+                .filter(missions::deleted_at.is_null())
+                .set((
+                    missions::status.eq(to.to_string()),
+                    missions::outcome_overridden_at.eq(now),
+                    missions::updated_at.eq(now),
+                ))
+                .returning(missions::id)
+                .get_result::<i32>(&mut conn)
+                .optional()
+                .context("Failed to execute mission update query")?
+                .ok_or(StatusConflict { mission_id })?;
+
+            refresh_stats_or_warn(&mut conn);
+            Ok(id)
+        })
+        .await??;
+
+        Ok(id)
+    }
+
+    async fn vote_outcome(&self, add_vote_entity: AddMissionOutcomeVoteEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_into(mission_outcome_votes::table)
+            .values(add_vote_entity)
+            .on_conflict((
+                mission_outcome_votes::mission_id,
+                mission_outcome_votes::brawler_id,
+            ))
+            .do_update()
+            .set(mission_outcome_votes::outcome.eq(excluded(mission_outcome_votes::outcome)))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_outcome_votes(&self, mission_id: i32) -> Result<Vec<MissionOutcomeVoteEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_outcome_votes::table
+            .filter(mission_outcome_votes::mission_id.eq(mission_id))
+            .select(MissionOutcomeVoteEntity::as_select())
+            .load::<MissionOutcomeVoteEntity>(&mut conn)?;
+        Ok(result)
+    }
 }
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into};
+
+use crate::{
+    domain::{
+        entities::mission_ratings::{AddMissionRatingEntity, MissionRatingEntity},
+        repositories::mission_rating::MissionRatingRepository,
+        value_objects::mission_rating_model::ReputationModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_ratings},
+};
+
+pub struct MissionRatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionRatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionRatingRepository for MissionRatingPostgres {
+    async fn rate(&self, add_mission_rating_entity: AddMissionRatingEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_ratings::table)
+            .values(add_mission_rating_entity)
+            .returning(mission_ratings::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_received(&self, brawler_id: i32) -> Result<Vec<MissionRatingEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_ratings::table
+            .filter(mission_ratings::ratee_id.eq(brawler_id))
+            .select(MissionRatingEntity::as_select())
+            .order_by(mission_ratings::created_at.desc())
+            .load::<MissionRatingEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_reputation(&self, brawler_id: i32) -> Result<ReputationModel> {
+        let sql = r#"
+            SELECT $1 AS brawler_id,
+                    COALESCE(AVG(mr.score), 0)::FLOAT8 AS reputation_score,
+                    COUNT(mr.id) AS rating_count
+            FROM mission_ratings mr
+            WHERE mr.ratee_id = $1
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<ReputationModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_reputations(&self, brawler_ids: Vec<i32>) -> Result<Vec<ReputationModel>> {
+        let sql = r#"
+            SELECT mr.ratee_id AS brawler_id,
+                    AVG(mr.score)::FLOAT8 AS reputation_score,
+                    COUNT(mr.id) AS rating_count
+            FROM mission_ratings mr
+            WHERE mr.ratee_id = ANY($1)
+            GROUP BY mr.ratee_id
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Array<diesel::sql_types::Int4>, _>(brawler_ids)
+            .load::<ReputationModel>(&mut conn)?;
+
+        Ok(result)
+    }
+}
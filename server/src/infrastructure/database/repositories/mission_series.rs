@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, PgExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+    dsl::{now, update},
+    insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            mission_series::{
+                AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity,
+            },
+            missions::{AddMissionEntity, MissionEntity},
+        },
+        repositories::mission_series::MissionSeriesRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_series, missions},
+    },
+};
+
+pub struct MissionSeriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionSeriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+
+    fn advance(
+        conn: &mut PgConnection,
+        series_id: i32,
+        occurrence: NaiveDateTime,
+        next_occurrence_at: Option<NaiveDateTime>,
+    ) -> Result<bool> {
+        let advanced = update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .filter(mission_series::cancelled_at.is_null())
+            .filter(mission_series::next_occurrence_at.eq(occurrence))
+            .set((
+                mission_series::next_occurrence_at.eq(next_occurrence_at),
+                mission_series::occurrence_count.eq(mission_series::occurrence_count + 1),
+            ))
+            .execute(conn)?;
+        Ok(advanced > 0)
+    }
+}
+
+#[async_trait]
+impl MissionSeriesRepository for MissionSeriesPostgres {
+    async fn create(&self, add_mission_series_entity: AddMissionSeriesEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_series::table)
+            .values(add_mission_series_entity)
+            .returning(mission_series::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, series_id: i32) -> Result<MissionSeriesEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::id.eq(series_id))
+            .select(MissionSeriesEntity::as_select())
+            .first::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_by_chief(&self, chief_id: i32) -> Result<Vec<MissionSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::chief_id.eq(chief_id))
+            .select(MissionSeriesEntity::as_select())
+            .order_by(mission_series::created_at.desc())
+            .load::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_occurrences(&self, series_id: i32) -> Result<Vec<MissionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = missions::table
+            .filter(missions::series_id.eq(series_id))
+            .filter(missions::deleted_at.is_null())
+            .select(MissionEntity::as_select())
+            .order_by(missions::scheduled_for.desc())
+            .load::<MissionEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        series_id: i32,
+        occurrence: Option<NaiveDateTime>,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mut query = update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .filter(mission_series::cancelled_at.is_null())
+            .into_boxed();
+        if edit_mission_series_entity.next_occurrence_at.is_some() {
+            query =
+                query.filter(mission_series::next_occurrence_at.is_not_distinct_from(occurrence));
+        }
+        let edited = query.set(edit_mission_series_entity).execute(&mut conn)?;
+        Ok(edited > 0)
+    }
+
+    async fn cancel(&self, series_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        update(mission_series::table)
+            .filter(mission_series::id.eq(series_id))
+            .filter(mission_series::cancelled_at.is_null())
+            .set((
+                mission_series::cancelled_at.eq(now),
+                mission_series::next_occurrence_at.eq(None::<NaiveDateTime>),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_due(&self, until: NaiveDateTime) -> Result<Vec<MissionSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_series::table
+            .filter(mission_series::cancelled_at.is_null())
+            .filter(mission_series::next_occurrence_at.le(until))
+            .select(MissionSeriesEntity::as_select())
+            .order_by(mission_series::next_occurrence_at.asc())
+            .load::<MissionSeriesEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn materialize(
+        &self,
+        series_id: i32,
+        occurrence: NaiveDateTime,
+        next_occurrence_at: Option<NaiveDateTime>,
+        add_mission_entity: AddMissionEntity,
+    ) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mission_id = conn.transaction::<Option<i32>, anyhow::Error, _>(|conn| {
+            if !Self::advance(conn, series_id, occurrence, next_occurrence_at)? {
+                return Ok(None);
+            }
+
+            let mission_id = insert_into(missions::table)
+                .values(add_mission_entity)
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(Some(mission_id))
+        })?;
+
+        Ok(mission_id)
+    }
+
+    async fn skip(
+        &self,
+        series_id: i32,
+        occurrence: NaiveDateTime,
+        next_occurrence_at: Option<NaiveDateTime>,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = Self::advance(&mut conn, series_id, occurrence, next_occurrence_at)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, dsl::delete, insert_into,
+};
+
+use crate::{
+    domain::{
+        entities::mission_templates::{AddMissionTemplateEntity, MissionTemplateEntity},
+        repositories::mission_template::MissionTemplateRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_templates},
+};
+
+pub struct MissionTemplatePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionTemplatePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionTemplateRepository for MissionTemplatePostgres {
+    async fn create(&self, add_mission_template_entity: AddMissionTemplateEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = insert_into(mission_templates::table)
+            .values(add_mission_template_entity)
+            .returning(mission_templates::id)
+            .get_result::<i32>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_one(&self, template_id: i32) -> Result<MissionTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_templates::table
+            .filter(mission_templates::id.eq(template_id))
+            .select(MissionTemplateEntity::as_select())
+            .first::<MissionTemplateEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_by_owner(&self, owner_id: i32) -> Result<Vec<MissionTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_templates::table
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .select(MissionTemplateEntity::as_select())
+            .order_by(mission_templates::updated_at.desc())
+            .load::<MissionTemplateEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn remove(&self, template_id: i32, owner_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let removed = delete(mission_templates::table)
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .execute(&mut conn)?;
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Template not found"));
+        }
+        Ok(())
+    }
+}
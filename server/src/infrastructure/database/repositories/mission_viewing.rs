@@ -2,17 +2,33 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+    dsl::{count, count_star, sql},
+    sql_types::{Bool, Float, Text},
+};
 
 use crate::{
     domain::{
-        entities::missions::MissionEntity,
+        entities::{
+            crew_memberships::CrewMemberShips, mission_revisions::MissionRevisionEntity,
+            mission_role_slots::MissionRoleSlotEntity, missions::MissionEntity,
+        },
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::{brawler_model::BrawlerModel, mission_filter::MissionFilter},
+        value_objects::{
+            brawler_model::{BrawlerModel, avatar_or_default},
+            mission_filter::MissionFilter,
+            mission_statuses::MissionStatuses,
+            recommendation_model::RecommendationSignalModel,
+        },
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
-        schema::{crew_memberships, missions},
+        schema::{
+            brawler_follows, crew_memberships, mission_objectives, mission_revisions,
+            mission_role_slots, missions,
+        },
     },
 };
 pub struct MissionViewingPostgres {
@@ -35,8 +51,7 @@ impl MissionViewingRepository for MissionViewingPostgres {
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = i64::try_from(value)?;
-        Ok(count)
+        Ok(value)
     }
 
     async fn get_one(&self, mission_id: i32) -> Result<MissionEntity> {
@@ -73,19 +88,93 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(value)
     }
 
+    async fn search(
+        &self,
+        mission_filter: &MissionFilter,
+        q: &str,
+    ) -> Result<Vec<(MissionEntity, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let matches = sql::<Bool>("missions.search_vector @@ websearch_to_tsquery('english', ")
+            .bind::<Text, _>(q.to_string())
+            .sql(")");
+        let rank = sql::<Float>("ts_rank(missions.search_vector, websearch_to_tsquery('english', ")
+            .bind::<Text, _>(q.to_string())
+            .sql("))");
+        let snippet = sql::<Text>(
+            "ts_headline('english', missions.name || ' ' || coalesce(missions.description, ''), websearch_to_tsquery('english', ",
+        )
+        .bind::<Text, _>(q.to_string())
+        .sql("), 'MaxFragments=2, MaxWords=20, MinWords=5')");
+
+        let mut query = missions::table
+            .filter(missions::deleted_at.is_null())
+            .filter(matches)
+            .select((MissionEntity::as_select(), snippet))
+            .into_boxed();
+
+        if let Some(status) = &mission_filter.status {
+            query = query.filter(missions::status.eq(status.to_string()));
+        };
+        if let Some(name) = &mission_filter.name {
+            query = query.filter(missions::name.ilike(format!("%{}%", name)));
+        };
+
+        let result = query
+            .order_by((rank.desc(), missions::created_at.desc()))
+            .load::<(MissionEntity, String)>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn autocomplete(&self, terms: &[String], limit: i64) -> Result<Vec<(i32, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Every term is matched as a prefix, so "drag sla" finds "Dragon Slayer".
+        let prefix_query = terms
+            .iter()
+            .map(|term| format!("{}:*", term))
+            .collect::<Vec<_>>()
+            .join(" & ");
+
+        let result = missions::table
+            .filter(missions::deleted_at.is_null())
+            .filter(
+                sql::<Bool>("missions.search_vector @@ to_tsquery('english', ")
+                    .bind::<Text, _>(prefix_query.clone())
+                    .sql(")"),
+            )
+            .select((missions::id, missions::name))
+            .order_by((
+                sql::<Float>("ts_rank(missions.search_vector, to_tsquery('english', ")
+                    .bind::<Text, _>(prefix_query)
+                    .sql("))")
+                    .desc(),
+                missions::created_at.desc(),
+            ))
+            .limit(limit)
+            .load::<(i32, String)>(&mut conn)?;
+
+        Ok(result)
+    }
+
     async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
         let sql = r#"
-            SELECT b.display_name,
-                    COALESCE(b.avatar_url, '') AS avatar_url,
-                    COALESCE(s.success_count, 0) AS mission_success_count,
-                    COALESCE(j.joined_count, 0) AS mission_joined_count
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    COALESCE(s.success_count, 0)::INT4 AS mission_success_count,
+                    COALESCE(j.joined_count, 0)::INT4 AS mission_join_count,
+                    COALESCE(r.reputation_score, 0)::FLOAT8 AS reputation_score,
+                    COALESCE(r.rating_count, 0) AS rating_count,
+                    cm.role
             FROM crew_memberships cm
             INNER JOIN brawlers b ON b.id = cm.brawler_id
             LEFT JOIN (
                 SELECT cm2.brawler_id, COUNT(*) AS success_count
                 FROM crew_memberships cm2
                 INNER JOIN missions m2 ON m2.id = cm2.mission_id
-                WHERE m2.status = 'success'
+                WHERE m2.status = $2
                 GROUP BY cm2.brawler_id
             ) s ON s.brawler_id = b.id
             LEFT JOIN (
@@ -93,14 +182,203 @@ impl MissionViewingRepository for MissionViewingPostgres {
                 FROM crew_memberships cm3
                 GROUP BY cm3.brawler_id
             ) j ON j.brawler_id = b.id
+            LEFT JOIN (
+                SELECT mr.ratee_id, AVG(mr.score) AS reputation_score, COUNT(*) AS rating_count
+                FROM mission_ratings mr
+                GROUP BY mr.ratee_id
+            ) r ON r.ratee_id = b.id
             WHERE cm.mission_id = $1
         "#;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let brawler_list = diesel::sql_query(sql)
+        let brawler_list: Vec<BrawlerModel> = diesel::sql_query(sql)
             .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .load::<BrawlerModel>(&mut conn)?;
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Completed.to_string())
+            .load::<BrawlerModel>(&mut conn)?
+            .into_iter()
+            .map(|mut brawler| {
+                brawler.avatar_url = avatar_or_default(brawler.avatar_url, brawler.brawler_id);
+                brawler
+            })
+            .collect();
 
         Ok(brawler_list)
     }
+
+    async fn get_crew_ids(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .select(crew_memberships::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_followed(&self, brawler_id: i32) -> Result<Vec<MissionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let followees = || {
+            brawler_follows::table
+                .filter(brawler_follows::follower_id.eq(brawler_id))
+                .select(brawler_follows::followee_id)
+        };
+        let joined_by_followees = crew_memberships::table
+            .filter(crew_memberships::brawler_id.eq_any(followees()))
+            .select(crew_memberships::mission_id);
+
+        let result = missions::table
+            .filter(missions::deleted_at.is_null())
+            .filter(
+                missions::chief_id
+                    .eq_any(followees())
+                    .or(missions::id.eq_any(joined_by_followees)),
+            )
+            .select(MissionEntity::as_select())
+            .order_by(missions::created_at.desc())
+            .load::<MissionEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_revisions(&self, mission_id: i32) -> Result<Vec<MissionRevisionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = mission_revisions::table
+            .filter(mission_revisions::mission_id.eq(mission_id))
+            .order_by(mission_revisions::version.asc())
+            .select(MissionRevisionEntity::as_select())
+            .load::<MissionRevisionEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn objective_progress(&self, mission_id: i32) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (total, done) = mission_objectives::table
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .select((count_star(), count(mission_objectives::completed_at)))
+            .first::<(i64, i64)>(&mut conn)?;
+
+        if total == 0 {
+            return Ok(None);
+        }
+        Ok(Some((done * 100 / total) as i32))
+    }
+
+    async fn pending_required_objectives(&self, mission_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let value = mission_objectives::table
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .filter(mission_objectives::required.eq(true))
+            .filter(mission_objectives::completed_at.is_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(value)
+    }
+
+    async fn get_recommendation_signals(
+        &self,
+        brawler_id: i32,
+        max_crew_per_mission: i64,
+    ) -> Result<Vec<RecommendationSignalModel>> {
+        let sql = r#"
+            WITH history AS (
+                SELECT m.tags
+                FROM missions m
+                WHERE m.status = $2
+                    AND m.deleted_at IS NULL
+                    AND (
+                        m.chief_id = $1
+                        OR EXISTS (
+                            SELECT 1 FROM crew_memberships cm
+                            WHERE cm.mission_id = m.id AND cm.brawler_id = $1
+                        )
+                    )
+            ),
+            tag_weights AS (
+                SELECT t.tag,
+                        COUNT(*)::FLOAT8 / (SELECT GREATEST(COUNT(*), 1) FROM history) AS weight
+                FROM history h, unnest(h.tags) AS t(tag)
+                GROUP BY t.tag
+            ),
+            followees AS (
+                SELECT bf.followee_id FROM brawler_follows bf WHERE bf.follower_id = $1
+            )
+            SELECT m.id AS mission_id,
+                    LEAST(
+                        COALESCE((SELECT SUM(tw.weight) FROM tag_weights tw WHERE tw.tag = ANY(m.tags)), 0),
+                        1
+                    )::FLOAT8 AS tag_affinity,
+                    (
+                        (SELECT COUNT(*) FROM followees f WHERE f.followee_id = m.chief_id)
+                        + (
+                            SELECT COUNT(*) FROM crew_memberships cm
+                            INNER JOIN followees f ON f.followee_id = cm.brawler_id
+                            WHERE cm.mission_id = m.id
+                        )
+                    ) AS followee_count,
+                    (SELECT AVG(mr.score)::FLOAT8 FROM mission_ratings mr WHERE mr.ratee_id = m.chief_id) AS chief_reputation,
+                    m.created_at
+            FROM missions m
+            WHERE m.status = $3
+                AND m.deleted_at IS NULL
+                AND m.chief_id <> $1
+                AND (SELECT COUNT(*) FROM crew_memberships cm WHERE cm.mission_id = m.id)
+                    < COALESCE(m.max_crew, $4)
+                AND NOT EXISTS (
+                    SELECT 1 FROM crew_memberships cm
+                    WHERE cm.mission_id = m.id AND cm.brawler_id = $1
+                )
+                AND NOT EXISTS (
+                    SELECT 1 FROM brawler_blocks bb
+                    WHERE (bb.blocker_id = m.chief_id AND bb.blocked_id = $1)
+                        OR (bb.blocker_id = $1 AND bb.blocked_id = m.chief_id)
+                )
+                AND (
+                    NOT m.guild_only
+                    OR EXISTS (
+                        SELECT 1 FROM guild_memberships gm
+                        WHERE gm.guild_id = m.guild_id AND gm.brawler_id = $1
+                    )
+                )
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Completed.to_string())
+            .bind::<diesel::sql_types::VarChar, _>(MissionStatuses::Open.to_string())
+            .bind::<diesel::sql_types::BigInt, _>(max_crew_per_mission)
+            .load::<RecommendationSignalModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_crew_memberships(&self, mission_id: i32) -> Result<Vec<CrewMemberShips>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .select(CrewMemberShips::as_select())
+            .load::<CrewMemberShips>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_role_slots(&self, mission_id: i32) -> Result<Vec<MissionRoleSlotEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_role_slots::table
+            .filter(mission_role_slots::mission_id.eq(mission_id))
+            .order_by(mission_role_slots::role.asc())
+            .select(MissionRoleSlotEntity::as_select())
+            .load::<MissionRoleSlotEntity>(&mut conn)?;
+
+        Ok(result)
+    }
 }
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, dsl::sql, insert_into,
+    sql_types::BigInt,
+};
+
+use crate::{
+    domain::{
+        entities::points_ledger::{AddPointsLedgerEntity, PointsLedgerEntity},
+        repositories::points_ledger::PointsLedgerRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::points_ledger},
+};
+
+pub struct PointsLedgerPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PointsLedgerPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PointsLedgerRepository for PointsLedgerPostgres {
+    async fn append(&self, entries: Vec<AddPointsLedgerEntity>) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let inserted = insert_into(points_ledger::table)
+            .values(&entries)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+        Ok(inserted)
+    }
+
+    async fn get_by_source(
+        &self,
+        source: &str,
+        source_id: i32,
+        source_at: NaiveDateTime,
+    ) -> Result<Vec<PointsLedgerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = points_ledger::table
+            .filter(points_ledger::source.eq(source))
+            .filter(points_ledger::source_id.eq(source_id))
+            .filter(points_ledger::source_at.eq(source_at))
+            .select(PointsLedgerEntity::as_select())
+            .load::<PointsLedgerEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_total(&self, brawler_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let total = points_ledger::table
+            .filter(points_ledger::brawler_id.eq(brawler_id))
+            .select(sql::<BigInt>("COALESCE(SUM(points), 0)"))
+            .first::<i64>(&mut conn)?;
+        Ok(total)
+    }
+
+    async fn get_history(
+        &self,
+        brawler_id: i32,
+        before_id: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<PointsLedgerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = points_ledger::table
+            .filter(points_ledger::brawler_id.eq(brawler_id))
+            .into_boxed();
+
+        if let Some(before_id) = before_id {
+            query = query.filter(points_ledger::id.lt(before_id));
+        }
+
+        let result = query
+            .select(PointsLedgerEntity::as_select())
+            .order_by(points_ledger::id.desc())
+            .limit(limit)
+            .load::<PointsLedgerEntity>(&mut conn)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, dsl::delete,
+    dsl::exists, insert_into, select,
+};
+
+use crate::{
+    domain::{
+        entities::{brawler_blocks::BrawlerBlockEntity, brawler_follows::BrawlerFollowEntity},
+        repositories::social::SocialRepository,
+        value_objects::{brawler_model::avatar_or_default, social_model::SocialBrawlerModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_blocks, brawler_follows},
+    },
+};
+
+pub struct SocialPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl SocialPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+
+    fn load_brawlers(&self, sql: &str, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .load::<SocialBrawlerModel>(&mut conn)?
+            .into_iter()
+            .map(|mut brawler| {
+                brawler.avatar_url = avatar_or_default(brawler.avatar_url, brawler.brawler_id);
+                brawler
+            })
+            .collect();
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl SocialRepository for SocialPostgres {
+    async fn follow(&self, brawler_follow_entity: BrawlerFollowEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_into(brawler_follows::table)
+            .values(brawler_follow_entity)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn unfollow(&self, brawler_follow_entity: BrawlerFollowEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(brawler_follows::table)
+            .filter(brawler_follows::follower_id.eq(brawler_follow_entity.follower_id))
+            .filter(brawler_follows::followee_id.eq(brawler_follow_entity.followee_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_following(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    f.created_at AS since
+            FROM brawler_follows f
+            INNER JOIN brawlers b ON b.id = f.followee_id
+            WHERE f.follower_id = $1
+            ORDER BY f.created_at DESC
+        "#;
+        self.load_brawlers(sql, brawler_id)
+    }
+
+    async fn get_followers(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    f.created_at AS since
+            FROM brawler_follows f
+            INNER JOIN brawlers b ON b.id = f.follower_id
+            WHERE f.followee_id = $1
+            ORDER BY f.created_at DESC
+        "#;
+        self.load_brawlers(sql, brawler_id)
+    }
+
+    async fn get_friends(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        // Friends are mutual follows; "since" is when the second follow happened.
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    GREATEST(f.created_at, back.created_at) AS since
+            FROM brawler_follows f
+            INNER JOIN brawler_follows back
+                ON back.follower_id = f.followee_id AND back.followee_id = f.follower_id
+            INNER JOIN brawlers b ON b.id = f.followee_id
+            WHERE f.follower_id = $1
+            ORDER BY since DESC
+        "#;
+        self.load_brawlers(sql, brawler_id)
+    }
+
+    async fn block(&self, brawler_block_entity: BrawlerBlockEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<(), anyhow::Error, _>(|conn| {
+            let (blocker_id, blocked_id) = (
+                brawler_block_entity.blocker_id,
+                brawler_block_entity.blocked_id,
+            );
+
+            insert_into(brawler_blocks::table)
+                .values(brawler_block_entity)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            // Blocking severs the follow relation in both directions.
+            delete(brawler_follows::table)
+                .filter(
+                    brawler_follows::follower_id
+                        .eq(blocker_id)
+                        .and(brawler_follows::followee_id.eq(blocked_id))
+                        .or(brawler_follows::follower_id
+                            .eq(blocked_id)
+                            .and(brawler_follows::followee_id.eq(blocker_id))),
+                )
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn unblock(&self, brawler_block_entity: BrawlerBlockEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        delete(brawler_blocks::table)
+            .filter(brawler_blocks::blocker_id.eq(brawler_block_entity.blocker_id))
+            .filter(brawler_blocks::blocked_id.eq(brawler_block_entity.blocked_id))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn get_blocked(&self, brawler_id: i32) -> Result<Vec<SocialBrawlerModel>> {
+        let sql = r#"
+            SELECT b.id AS brawler_id,
+                    b.display_name,
+                    b.avatar_url,
+                    bb.created_at AS since
+            FROM brawler_blocks bb
+            INNER JOIN brawlers b ON b.id = bb.blocked_id
+            WHERE bb.blocker_id = $1
+            ORDER BY bb.created_at DESC
+        "#;
+        self.load_brawlers(sql, brawler_id)
+    }
+
+    async fn is_blocked_between(&self, brawler_id: i32, other_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = select(exists(
+            brawler_blocks::table.filter(
+                brawler_blocks::blocker_id
+                    .eq(brawler_id)
+                    .and(brawler_blocks::blocked_id.eq(other_id))
+                    .or(brawler_blocks::blocker_id
+                        .eq(other_id)
+                        .and(brawler_blocks::blocked_id.eq(brawler_id))),
+            ),
+        ))
+        .get_result::<bool>(&mut conn)?;
+        Ok(result)
+    }
+}
@@ -1,5 +1,74 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    activity_events (id) {
+        id -> Int4,
+        actor_id -> Int4,
+        mission_id -> Int4,
+        #[max_length = 50]
+        kind -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    badges (id) {
+        id -> Int4,
+        #[max_length = 100]
+        code -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 512]
+        icon_url -> Nullable<Varchar>,
+        #[max_length = 100]
+        metric -> Varchar,
+        threshold -> Int4,
+        window_days -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_avatar_variants (brawler_id, size) {
+        brawler_id -> Int4,
+        size -> Int4,
+        url -> Text,
+        public_id -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_badges (brawler_id, badge_id) {
+        brawler_id -> Int4,
+        badge_id -> Int4,
+        awarded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_blocks (blocker_id, blocked_id) {
+        blocker_id -> Int4,
+        blocked_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    brawler_follows (follower_id, followee_id) {
+        follower_id -> Int4,
+        followee_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -15,6 +84,8 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        is_admin -> Bool,
+        banned_at -> Nullable<Timestamp>,
     }
 }
 
@@ -23,10 +94,189 @@ diesel::table! {
         mission_id -> Int4,
         brawler_id -> Int4,
         joined_at -> Timestamp,
+        #[max_length = 50]
+        role -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    guild_invitations (guild_id, brawler_id) {
+        guild_id -> Int4,
+        brawler_id -> Int4,
+        invited_by -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    guild_memberships (guild_id, brawler_id) {
+        guild_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 50]
+        role -> Varchar,
+        joined_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    guilds (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        owner_id -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    matchmaking_tickets (brawler_id) {
+        brawler_id -> Int4,
+        #[max_length = 50]
+        category -> Nullable<Varchar>,
+        #[max_length = 50]
+        role -> Nullable<Varchar>,
+        min_reputation -> Nullable<Float8>,
+        #[max_length = 50]
+        status -> Varchar,
+        mission_id -> Nullable<Int4>,
+        proposed_at -> Nullable<Timestamp>,
+        declined_mission_ids -> Array<Int4>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_attachments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        uploaded_by -> Int4,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        #[max_length = 100]
+        mime_type -> Varchar,
+        size_bytes -> Int4,
+        url -> Text,
+        public_id -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_invitations (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        invited_by -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_objectives (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        required -> Bool,
+        completed_by -> Nullable<Int4>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_outcome_votes (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 50]
+        outcome -> Varchar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int4,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_revisions (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        version -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        edited_by -> Int4,
+        edited_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_role_slots (mission_id, role) {
+        mission_id -> Int4,
+        #[max_length = 50]
+        role -> Varchar,
+        capacity -> Int4,
+    }
+}
+
+diesel::table! {
+    mission_series (id) {
+        id -> Int4,
+        chief_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        max_crew -> Nullable<Int4>,
+        tags -> Array<Text>,
+        min_reputation -> Nullable<Float8>,
+        guild_id -> Nullable<Int4>,
+        guild_only -> Bool,
+        #[max_length = 50]
+        frequency -> Varchar,
+        weekdays -> Array<Int4>,
+        starts_at -> Timestamp,
+        ends_at -> Nullable<Timestamp>,
+        max_occurrences -> Nullable<Int4>,
+        occurrence_count -> Int4,
+        next_occurrence_at -> Nullable<Timestamp>,
+        cancelled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_templates (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        max_crew -> Nullable<Int4>,
+        tags -> Array<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     missions (id) {
         id -> Int4,
         #[max_length = 255]
@@ -38,11 +288,104 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        finished_at -> Nullable<Timestamp>,
+        min_reputation -> Nullable<Float8>,
+        guild_id -> Nullable<Int4>,
+        guild_only -> Bool,
+        search_vector -> Tsvector,
+        max_crew -> Nullable<Int4>,
+        tags -> Array<Text>,
+        series_id -> Nullable<Int4>,
+        scheduled_for -> Nullable<Timestamp>,
+        version -> Int4,
+        consensus_outcome -> Bool,
+        outcome_overridden_at -> Nullable<Timestamp>,
+        require_objectives -> Bool,
+        banner_url -> Nullable<Text>,
+        banner_public_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    points_ledger (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 100]
+        source -> Varchar,
+        source_id -> Nullable<Int4>,
+        points -> Int4,
+        note -> Nullable<Text>,
+        issued_by -> Nullable<Int4>,
+        created_at -> Timestamp,
+        source_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    seasons (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        created_at -> Timestamp,
     }
 }
 
+diesel::joinable!(activity_events -> brawlers (actor_id));
+diesel::joinable!(activity_events -> missions (mission_id));
+diesel::joinable!(brawler_avatar_variants -> brawlers (brawler_id));
+diesel::joinable!(brawler_badges -> badges (badge_id));
+diesel::joinable!(brawler_badges -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(guild_invitations -> guilds (guild_id));
+diesel::joinable!(guild_memberships -> brawlers (brawler_id));
+diesel::joinable!(guild_memberships -> guilds (guild_id));
+diesel::joinable!(guilds -> brawlers (owner_id));
+diesel::joinable!(matchmaking_tickets -> brawlers (brawler_id));
+diesel::joinable!(matchmaking_tickets -> missions (mission_id));
+diesel::joinable!(mission_attachments -> brawlers (uploaded_by));
+diesel::joinable!(mission_attachments -> missions (mission_id));
+diesel::joinable!(mission_invitations -> missions (mission_id));
+diesel::joinable!(mission_objectives -> brawlers (completed_by));
+diesel::joinable!(mission_objectives -> missions (mission_id));
+diesel::joinable!(mission_outcome_votes -> brawlers (brawler_id));
+diesel::joinable!(mission_outcome_votes -> missions (mission_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_revisions -> brawlers (edited_by));
+diesel::joinable!(mission_revisions -> missions (mission_id));
+diesel::joinable!(mission_role_slots -> missions (mission_id));
+diesel::joinable!(mission_series -> brawlers (chief_id));
+diesel::joinable!(mission_series -> guilds (guild_id));
+diesel::joinable!(mission_templates -> brawlers (owner_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> guilds (guild_id));
+diesel::joinable!(missions -> mission_series (series_id));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    activity_events,
+    badges,
+    brawler_avatar_variants,
+    brawler_badges,
+    brawler_blocks,
+    brawler_follows,
+    brawlers,
+    crew_memberships,
+    guild_invitations,
+    guild_memberships,
+    guilds,
+    matchmaking_tickets,
+    mission_attachments,
+    mission_invitations,
+    mission_objectives,
+    mission_outcome_votes,
+    mission_ratings,
+    mission_revisions,
+    mission_role_slots,
+    mission_series,
+    mission_templates,
+    missions,
+    points_ledger,
+    seasons,
+);
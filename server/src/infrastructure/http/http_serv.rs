@@ -5,7 +5,7 @@ use axum::{
     Router,
     http::{
         Method, StatusCode,
-        header::{AUTHORIZATION, CONTENT_TYPE},
+        header::{AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MATCH},
     },
 };
 use tokio::net::TcpListener;
@@ -19,7 +19,10 @@ use tracing::info;
 
 use crate::{
     config::config_model::DotEnvyConfig,
-    infrastructure::{database::postgresql_connection::PgPoolSquad, http::routers},
+    infrastructure::{
+        database::postgresql_connection::PgPoolSquad, http::routers,
+        image_storage::ImageStorageBackend,
+    },
 };
 
 fn static_serve() -> Router {
@@ -30,9 +33,12 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_serve(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Router {
     Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/brawler",
+            routers::brawlers::routes(Arc::clone(&db_pool), Arc::clone(&image_storage)),
+        )
         .nest(
             "/view",
             routers::mission_viewing::routes(Arc::clone(&db_pool)),
@@ -47,8 +53,44 @@ fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
         )
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool)),
+            routers::mission_management::routes(Arc::clone(&db_pool), Arc::clone(&image_storage)),
+        )
+        .nest(
+            "/attachments",
+            routers::mission_attachment::routes(Arc::clone(&db_pool), Arc::clone(&image_storage)),
+        )
+        .nest(
+            "/objectives",
+            routers::mission_objective::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/rating",
+            routers::mission_rating::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/leaderboard",
+            routers::leaderboard::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/achievements",
+            routers::achievement::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/progression",
+            routers::progression::routes(Arc::clone(&db_pool)),
+        )
+        .nest("/guild", routers::guild::routes(Arc::clone(&db_pool)))
+        .nest("/social", routers::social::routes(Arc::clone(&db_pool)))
+        .nest("/activity", routers::activity::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/mission-series",
+            routers::mission_series::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/matchmaking",
+            routers::matchmaking::routes(Arc::clone(&db_pool), Arc::clone(&image_storage)),
         )
+        .nest("/admin", routers::admin::routes(Arc::clone(&db_pool)))
         .nest(
             "/authentication",
             routers::authentication::routes(Arc::clone(&db_pool)),
@@ -56,10 +98,14 @@ fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
         .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
 }
 
-pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+pub async fn start(
+    config: Arc<DotEnvyConfig>,
+    db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<ImageStorageBackend>,
+) -> Result<()> {
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api", api_serve(db_pool))
+        .nest("/api", api_serve(db_pool, image_storage))
         // .fallback(default_router::health_check)
         // .route("/health_check", get(default_router::health_check)
         .layer(tower_http::timeout::TimeoutLayer::with_status_code(
@@ -80,7 +126,8 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
                     Method::OPTIONS,
                 ])
                 .allow_origin(Any)
-                .allow_headers([AUTHORIZATION, CONTENT_TYPE]),
+                .allow_headers([AUTHORIZATION, CONTENT_TYPE, IF_MATCH])
+                .expose_headers([ETAG]),
         )
         .layer(TraceLayer::new_for_http());
 
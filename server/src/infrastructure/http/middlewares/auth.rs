@@ -1,13 +1,52 @@
+use std::sync::Arc;
+
 use axum::{
-    extract::Request,
+    extract::{Request, State},
     http::{StatusCode, header},
     middleware::Next,
     response::Response,
 };
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::verify_token};
+use crate::{
+    config::config_loader::get_jwt_env,
+    domain::{entities::brawlers::BrawlerEntity, repositories::brawlers::BrawlerRepository},
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
+        jwt::verify_token,
+    },
+};
+
+pub async fn auth(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let user_id = verified_user_id(&req)?;
+    let brawler = find_active(db_pool, user_id).await?;
+
+    req.extensions_mut().insert(brawler.id);
+
+    Ok(next.run(req).await)
+}
+
+/// Same as `auth`, for routes only admins may use.
+pub async fn admin(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let user_id = verified_user_id(&req)?;
+    let brawler = find_active(db_pool, user_id).await?;
+    if !brawler.is_admin {
+        return Err(StatusCode::FORBIDDEN);
+    }
 
-pub async fn auth(mut req: Request, next: Next) -> Result<Response, StatusCode> {
+    req.extensions_mut().insert(brawler.id);
+
+    Ok(next.run(req).await)
+}
+
+fn verified_user_id(req: &Request) -> Result<i32, StatusCode> {
     let header = req
         .headers()
         .get(header::AUTHORIZATION)
@@ -24,12 +63,22 @@ pub async fn auth(mut req: Request, next: Next) -> Result<Response, StatusCode>
 
     let claims = verify_token(secret, token).map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    let user_id = claims
+    claims
         .sub
         .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+        .map_err(|_| StatusCode::UNAUTHORIZED)
+}
 
-    req.extensions_mut().insert(user_id);
+async fn find_active(db_pool: Arc<PgPoolSquad>, user_id: i32) -> Result<BrawlerEntity, StatusCode> {
+    // Looked up on every request, so a ban applies to tokens issued before it.
+    let brawler = BrawlerPostgres::new(db_pool)
+        .find_by_id(user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if brawler.banned_at.is_some() {
+        return Err(StatusCode::FORBIDDEN);
+    }
 
-    Ok(next.run(req).await)
+    Ok(brawler)
 }
@@ -1,3 +1,4 @@
 pub mod http_serv;
 pub mod middlewares;
+pub mod multipart;
 pub mod routers;
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use axum::extract::Multipart;
+
+use crate::domain::value_objects::upload_file::{MAX_FILE_BYTES, UploadFile};
+
+pub const FILE_FIELD: &str = "file";
+/// Room for the boundaries and part headers around the file itself.
+pub const BODY_LIMIT: usize = MAX_FILE_BYTES + 16 * 1024;
+/// Enough leading bytes for `infer` to recognise every accepted type.
+const SNIFF_BYTES: usize = 32;
+
+/// Streams the `file` field of a multipart body, giving up as soon as it grows past
+/// the size limit or its leading bytes are not one of `mime_types`.
+pub async fn read_file(multipart: &mut Multipart, mime_types: &[&str]) -> Result<UploadFile> {
+    while let Some(mut field) = multipart.next_field().await? {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+
+        let mut bytes = Vec::new();
+        let mut sniffed = false;
+        while let Some(chunk) = field.chunk().await? {
+            if bytes.len() + chunk.len() > MAX_FILE_BYTES {
+                return Err(anyhow::anyhow!(
+                    "file is too large, at most {} MB !!",
+                    MAX_FILE_BYTES / 1024 / 1024
+                ));
+            }
+            bytes.extend_from_slice(&chunk);
+
+            if !sniffed && bytes.len() >= SNIFF_BYTES {
+                UploadFile::sniff(&bytes, mime_types)?;
+                sniffed = true;
+            }
+        }
+
+        return UploadFile::new(bytes, mime_types);
+    }
+
+    Err(anyhow::anyhow!("missing `{}` field", FILE_FIELD))
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::achievement::AchievementUseCase,
+    domain::repositories::achievement::AchievementRepository,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::achievement::AchievementPostgres,
+    },
+};
+
+pub async fn get_definitions<T>(
+    State(user_case): State<Arc<AchievementUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: AchievementRepository + Send + Sync,
+{
+    match user_case.get_definitions().await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_awarded<T>(
+    State(user_case): State<Arc<AchievementUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: AchievementRepository + Send + Sync,
+{
+    match user_case.get_awarded(brawler_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let user_case = AchievementUseCase::new(Arc::new(achievement_repository));
+
+    Router::new()
+        .route("/", get(get_definitions))
+        .route("/brawler/{brawler_id}", get(get_awarded))
+        .with_state(Arc::new(user_case))
+}
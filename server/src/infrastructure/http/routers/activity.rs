@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::activity::ActivityUseCase,
+    domain::{
+        repositories::activity::ActivityRepository, value_objects::activity_model::ActivityFilter,
+    },
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::activity::ActivityPostgres},
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn get_global<T>(
+    State(user_case): State<Arc<ActivityUseCase<T>>>,
+    filter: Query<ActivityFilter>,
+) -> impl IntoResponse
+where
+    T: ActivityRepository + Send + Sync,
+{
+    match user_case.get_global(&filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_personal<T>(
+    State(user_case): State<Arc<ActivityUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    filter: Query<ActivityFilter>,
+) -> impl IntoResponse
+where
+    T: ActivityRepository + Send + Sync,
+{
+    match user_case.get_personal(user_id, &filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let activity_repository = ActivityPostgres::new(Arc::clone(&db_pool));
+    let user_case = ActivityUseCase::new(Arc::new(activity_repository));
+
+    let protected_routes = Router::new()
+        .route("/me", get(get_personal))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/", get(get_global))
+        .with_state(Arc::new(user_case))
+}
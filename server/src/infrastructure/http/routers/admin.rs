@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::use_cases::admin::AdminUseCase,
+    domain::repositories::{
+        achievement::AchievementRepository, brawlers::BrawlerRepository,
+        leaderboard::LeaderboardRepository,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievement::AchievementPostgres, brawlers::BrawlerPostgres,
+                leaderboard::LeaderboardPostgres,
+            },
+        },
+        http::middlewares::auth::admin,
+    },
+};
+
+type AdminState<T1, T2, T3> = State<Arc<AdminUseCase<T1, T2, T3>>>;
+
+pub async fn ban<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(username): Path<String>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: LeaderboardRepository + Send + Sync,
+{
+    match user_case.ban(username, true).await {
+        Ok(brawler_id) => {
+            (StatusCode::OK, format!("Brawler_id:{} banned", brawler_id)).into_response()
+        }
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn lift_ban<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(username): Path<String>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: LeaderboardRepository + Send + Sync,
+{
+    match user_case.ban(username, false).await {
+        Ok(brawler_id) => (
+            StatusCode::OK,
+            format!("Ban on Brawler_id:{} lifted", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let user_case = AdminUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LeaderboardPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    Router::new()
+        .route("/brawlers/{username}/ban", post(ban).delete(lift_ban))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), admin))
+        .with_state(Arc::new(user_case))
+}
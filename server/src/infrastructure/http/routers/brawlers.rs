@@ -1,42 +1,74 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse,
-    routing::post,
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{
+        StatusCode,
+        header::{CACHE_CONTROL, CONTENT_TYPE},
+    },
+    response::IntoResponse,
+    routing::{get, post},
 };
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_img::UploadBase64Img},
+        repositories::{
+            achievement::AchievementRepository, brawlers::BrawlerRepository,
+            image_storage::ImageStorage,
+        },
+        value_objects::{
+            base64_img::AVATAR_MIME_TYPES, brawler_model::RegisterBrawlerModel,
+            uploaded_img::UploadBase64Img, upstream_error::UpstreamError,
+        },
     },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        http::middlewares::auth::auth,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{achievement::AchievementPostgres, brawlers::BrawlerPostgres},
+        },
+        http::{middlewares::auth::auth, multipart},
+        image_storage::ImageStorageBackend,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = BrawlersUseCase::new(Arc::new(repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Router {
+    let repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let user_case = BrawlersUseCase::new(
+        Arc::new(repository),
+        Arc::new(achievement_repository),
+        image_storage,
+    );
 
     let protected_routes = Router::new()
-        .route("/avatar", post(upload_avatar))
-        .route_layer(axum::middleware::from_fn(auth));
+        .route("/avatar", post(upload_avatar).delete(remove_avatar))
+        .route(
+            "/avatar/upload",
+            post(upload_avatar_file).layer(DefaultBodyLimit::max(multipart::BODY_LIMIT)),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            auth,
+        ));
 
     Router::new()
         .merge(protected_routes)
         .route("/register", post(register))
+        .route("/profile/{brawler_id}", get(get_profile))
+        .route("/{brawler_id}/avatar/default", get(get_default_avatar))
         .with_state(Arc::new(user_case))
 }
 
-pub async fn register<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn register<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
     Json(model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
 {
     match user_case.register(model).await {
         Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
@@ -45,13 +77,31 @@ where
     }
 }
 
-pub async fn upload_avatar<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn get_profile<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.get_profile(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn upload_avatar<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
     Json(model): Json<UploadBase64Img>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
 {
     match user_case
         .upload_base64img(user_id, model.base64_string)
@@ -59,6 +109,73 @@ where
     {
         Ok(upload_img) => (StatusCode::OK, Json(upload_img)).into_response(),
 
+        Err(e) if e.is::<UpstreamError>() => {
+            (StatusCode::BAD_GATEWAY, e.to_string()).into_response()
+        }
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
+
+pub async fn upload_avatar_file<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    mut form: Multipart,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    let file = match multipart::read_file(&mut form, &AVATAR_MIME_TYPES).await {
+        Ok(file) => file,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+
+    match user_case.upload_avatar(user_id, file).await {
+        Ok(upload_img) => (StatusCode::OK, Json(upload_img)).into_response(),
+
+        Err(e) if e.is::<UpstreamError>() => {
+            (StatusCode::BAD_GATEWAY, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_avatar<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.remove_avatar(user_id).await {
+        Ok(_) => (StatusCode::OK, "Remove avatar completed!!").into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_default_avatar<T1, T2, T3>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: AchievementRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    let svg = user_case.get_default_avatar(brawler_id);
+
+    (
+        StatusCode::OK,
+        [
+            (CONTENT_TYPE, "image/svg+xml"),
+            // The image only depends on the id, so it never changes.
+            (CACHE_CONTROL, "public, max-age=31536000, immutable"),
+        ],
+        svg,
+    )
+        .into_response()
+}
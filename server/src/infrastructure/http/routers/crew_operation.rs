@@ -1,40 +1,58 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
-    extract::{Path, State},
+    Extension, Json, Router,
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
-    domain::repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, activity::ActivityRepository,
+            crew_operation::CrewOperationRepository, guild::GuildRepository,
+            mission_rating::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+            social::SocialRepository,
+        },
+        value_objects::mission_role_model::CrewRoleQuery,
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
+                achievement::AchievementPostgres, activity::ActivityPostgres,
+                crew_operation::CrewOperationPostgres, guild::GuildPostgres,
+                mission_rating::MissionRatingPostgres, mission_viewing::MissionViewingPostgres,
+                social::SocialPostgres,
             },
         },
         http::middlewares::auth::auth,
     },
 };
 
-pub async fn join<T1, T2>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+type CrewOperationState<T1, T2, T3, T4, T5, T6, T7> =
+    State<Arc<CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7>>>;
+
+pub async fn join<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    Query(query): Query<CrewRoleQuery>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
 {
-    match user_case.join(mission_id, user_id).await {
+    match user_case.join(mission_id, user_id, query.role).await {
         Ok(_) => (
             StatusCode::OK,
             format!("Join Mission_id:{} completed", mission_id),
@@ -45,14 +63,19 @@ where
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn leave<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
 {
     match user_case.leave(mission_id, user_id).await {
         Ok(_) => (
@@ -65,17 +88,135 @@ where
     }
 }
 
+pub async fn change_role<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<CrewRoleQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+{
+    let Some(role) = query.role else {
+        return (StatusCode::BAD_REQUEST, "Missing role").into_response();
+    };
+
+    match user_case.change_role(mission_id, user_id, role).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Change role of Mission_id:{} completed", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_invitations<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+{
+    match user_case.get_invitations(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept_invitation<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Query(query): Query<CrewRoleQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+{
+    match user_case
+        .accept_invitation(mission_id, user_id, query.role)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join Mission_id:{} completed", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn decline_invitation<T1, T2, T3, T4, T5, T6, T7>(
+    State(user_case): CrewOperationState<T1, T2, T3, T4, T5, T6, T7>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+{
+    match user_case.decline_invitation(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Decline Mission_id:{} completed", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let rating_repository = MissionRatingPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let guild_repository = GuildPostgres::new(Arc::clone(&db_pool));
+    let social_repository = SocialPostgres::new(Arc::clone(&db_pool));
+    let activity_repository = ActivityPostgres::new(Arc::clone(&db_pool));
     let user_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
         Arc::new(viewing_repositiory),
+        Arc::new(rating_repository),
+        Arc::new(achievement_repository),
+        Arc::new(guild_repository),
+        Arc::new(social_repository),
+        Arc::new(activity_repository),
     );
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
-        .route_layer(middleware::from_fn(auth))
+        .route("/role/{mission_id}", patch(change_role))
+        .route("/invitations", get(get_invitations))
+        .route("/invitations/{mission_id}/accept", post(accept_invitation))
+        .route("/invitations/{mission_id}", delete(decline_invitation))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::use_cases::guild::GuildUseCase,
+    domain::{
+        repositories::{guild::GuildRepository, social::SocialRepository},
+        value_objects::guild_model::{AddGuildModel, ChangeGuildRoleModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{guild::GuildPostgres, social::SocialPostgres},
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn create<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddGuildModel>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.create(user_id, model).await {
+        Ok(guild_id) => (StatusCode::CREATED, guild_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_one<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.get_one(guild_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_members<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.get_members(guild_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_stats<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.get_stats(guild_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn invite<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((guild_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.invite(guild_id, user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Invite brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_invitations<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.get_invitations(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.accept(guild_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join Guild_id:{} completed", guild_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn decline<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.decline(guild_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Decline Guild_id:{} completed", guild_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn leave<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.leave(guild_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Leave Guild_id:{} completed", guild_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn change_role<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((guild_id, brawler_id)): Path<(i32, i32)>,
+    Json(model): Json<ChangeGuildRoleModel>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case
+        .change_role(guild_id, user_id, brawler_id, model.role)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Change role of brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_member<T1, T2>(
+    State(user_case): State<Arc<GuildUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((guild_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: GuildRepository + Send + Sync,
+    T2: SocialRepository + Send + Sync,
+{
+    match user_case.remove_member(guild_id, user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let guild_repository = GuildPostgres::new(Arc::clone(&db_pool));
+    let social_repository = SocialPostgres::new(Arc::clone(&db_pool));
+    let user_case = GuildUseCase::new(Arc::new(guild_repository), Arc::new(social_repository));
+
+    let protected_routes = Router::new()
+        .route("/", post(create))
+        .route("/invitations", get(get_invitations))
+        .route("/{guild_id}/invite/{brawler_id}", post(invite))
+        .route("/{guild_id}/accept", post(accept))
+        .route("/{guild_id}/decline", delete(decline))
+        .route("/{guild_id}/leave", delete(leave))
+        .route("/{guild_id}/members/{brawler_id}", delete(remove_member))
+        .route("/{guild_id}/members/{brawler_id}/role", patch(change_role))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/{guild_id}", get(get_one))
+        .route("/{guild_id}/members", get(get_members))
+        .route("/{guild_id}/stats", get(get_stats))
+        .with_state(Arc::new(user_case))
+}
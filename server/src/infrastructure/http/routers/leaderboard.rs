@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::leaderboard::LeaderboardUseCase,
+    domain::{
+        repositories::leaderboard::LeaderboardRepository,
+        value_objects::leaderboard_model::LeaderboardFilter,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::leaderboard::LeaderboardPostgres,
+    },
+};
+
+pub async fn get_leaderboard<T>(
+    State(user_case): State<Arc<LeaderboardUseCase<T>>>,
+    filter: Query<LeaderboardFilter>,
+) -> impl IntoResponse
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    match user_case.get_leaderboard(&filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_seasons<T>(
+    State(user_case): State<Arc<LeaderboardUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    match user_case.get_seasons().await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let leaderboard_repository = LeaderboardPostgres::new(Arc::clone(&db_pool));
+    let user_case = LeaderboardUseCase::new(Arc::new(leaderboard_repository));
+
+    Router::new()
+        .route("/", get(get_leaderboard))
+        .route("/seasons", get(get_seasons))
+        .with_state(Arc::new(user_case))
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router, extract::State, http::StatusCode, middleware, response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::use_cases::{
+        crew_operation::CrewOperationUseCase, matchmaking::MatchmakingUseCase,
+        mission_management::MissionManagementUseCase,
+    },
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, activity::ActivityRepository,
+            crew_operation::CrewOperationRepository, guild::GuildRepository,
+            image_storage::ImageStorage, matchmaking::MatchmakingRepository,
+            mission_management::MissionManagementRepository,
+            mission_rating::MissionRatingRepository, mission_template::MissionTemplateRepository,
+            mission_viewing::MissionViewingRepository, social::SocialRepository,
+        },
+        value_objects::matchmaking_model::EnqueueModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievement::AchievementPostgres, activity::ActivityPostgres,
+                crew_operation::CrewOperationPostgres, guild::GuildPostgres,
+                matchmaking::MatchmakingPostgres, mission_management::MissionManagementPostgres,
+                mission_rating::MissionRatingPostgres, mission_template::MissionTemplatePostgres,
+                mission_viewing::MissionViewingPostgres, social::SocialPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+        image_storage::ImageStorageBackend,
+    },
+};
+
+type MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11> =
+    State<Arc<MatchmakingUseCase<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>>>;
+
+pub async fn enqueue<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>(
+    State(user_case): MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<EnqueueModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    match user_case.enqueue(user_id, model).await {
+        Ok(_) => (StatusCode::CREATED, "Joined the matchmaking queue").into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_ticket<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>(
+    State(user_case): MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    match user_case.get_ticket(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn leave<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>(
+    State(user_case): MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    match user_case.leave(user_id).await {
+        Ok(_) => (StatusCode::OK, "Left the matchmaking queue").into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn accept<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>(
+    State(user_case): MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    match user_case.accept(user_id).await {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Join Mission_id:{} completed", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn decline<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>(
+    State(user_case): MatchmakingState<T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionRatingRepository + Send + Sync,
+    T4: AchievementRepository + Send + Sync,
+    T5: GuildRepository + Send + Sync,
+    T6: SocialRepository + Send + Sync,
+    T7: ActivityRepository + Send + Sync,
+    T8: MatchmakingRepository + Send + Sync,
+    T9: MissionManagementRepository + Send + Sync,
+    T10: MissionTemplateRepository + Send + Sync,
+    T11: ImageStorage + Send + Sync,
+{
+    match user_case.decline(user_id).await {
+        Ok(_) => (StatusCode::OK, "Proposal declined").into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Router {
+    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool)));
+    let rating_repository = Arc::new(MissionRatingPostgres::new(Arc::clone(&db_pool)));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let guild_repository = Arc::new(GuildPostgres::new(Arc::clone(&db_pool)));
+    let social_repository = SocialPostgres::new(Arc::clone(&db_pool));
+    let activity_repository = Arc::new(ActivityPostgres::new(Arc::clone(&db_pool)));
+    let matchmaking_repository = MatchmakingPostgres::new(Arc::clone(&db_pool));
+    let management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let template_repository = MissionTemplatePostgres::new(Arc::clone(&db_pool));
+    let crew_operation_user_case = CrewOperationUseCase::new(
+        Arc::new(crew_operation_repository),
+        Arc::clone(&viewing_repositiory),
+        Arc::clone(&rating_repository),
+        Arc::new(achievement_repository),
+        Arc::clone(&guild_repository),
+        Arc::new(social_repository),
+        Arc::clone(&activity_repository),
+    );
+    let management_user_case = MissionManagementUseCase::new(
+        Arc::new(management_repository),
+        Arc::clone(&viewing_repositiory),
+        guild_repository,
+        activity_repository,
+        Arc::new(template_repository),
+        image_storage,
+    );
+    let user_case = MatchmakingUseCase::new(
+        Arc::new(crew_operation_user_case),
+        Arc::new(management_user_case),
+        viewing_repositiory,
+        rating_repository,
+        Arc::new(matchmaking_repository),
+    );
+
+    Router::new()
+        .route("/", post(enqueue).get(get_ticket).delete(leave))
+        .route("/accept", post(accept))
+        .route("/decline", post(decline))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
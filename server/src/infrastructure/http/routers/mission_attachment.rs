@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+
+use crate::{
+    application::use_cases::mission_attachment::MissionAttachmentUseCase,
+    domain::{
+        repositories::{
+            image_storage::ImageStorage, mission_attachment::MissionAttachmentRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_attachment_model::UploadAttachmentModel, uploaded_img::UploadBase64Img,
+            upstream_error::UpstreamError,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_attachment::MissionAttachmentPostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+        image_storage::ImageStorageBackend,
+    },
+};
+
+pub async fn get_all<T1, T2, T3>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2, T3>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.get_all(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2, T3>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadAttachmentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.add(mission_id, user_id, model).await {
+        Ok(attachment_id) => (StatusCode::CREATED, attachment_id.to_string()).into_response(),
+
+        Err(e) if e.is::<UpstreamError>() => {
+            (StatusCode::BAD_GATEWAY, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2, T3>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, attachment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.remove(mission_id, attachment_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove attachment_id: {} completed!!", attachment_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn upload_banner<T1, T2, T3>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadBase64Img>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case
+        .upload_banner(mission_id, user_id, model.base64_string)
+        .await
+    {
+        Ok(upload_img) => (StatusCode::OK, Json(upload_img)).into_response(),
+
+        Err(e) if e.is::<UpstreamError>() => {
+            (StatusCode::BAD_GATEWAY, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_banner<T1, T2, T3>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: ImageStorage + Send + Sync,
+{
+    match user_case.remove_banner(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove banner of mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Router {
+    let attachment_repository = MissionAttachmentPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionAttachmentUseCase::new(
+        Arc::new(attachment_repository),
+        Arc::new(viewing_repositiory),
+        image_storage,
+    );
+
+    let protected_routes = Router::new()
+        .route("/{mission_id}", post(add))
+        .route(
+            "/{mission_id}/banner",
+            put(upload_banner).delete(remove_banner),
+        )
+        .route("/{mission_id}/{attachment_id}", delete(remove))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/{mission_id}", get(get_all))
+        .with_state(Arc::new(user_case))
+}
@@ -3,41 +3,59 @@ use std::sync::Arc;
 use axum::{
     Extension, Json, Router,
     extract::{Path, State},
-    http::StatusCode,
+    http::{
+        HeaderMap, StatusCode,
+        header::{ETAG, IF_MATCH},
+    },
     middleware,
     response::IntoResponse,
-    routing::{delete, patch, post},
+    routing::{delete, get, patch, post, put},
 };
 
 use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
     domain::{
         repositories::{
+            activity::ActivityRepository, guild::GuildRepository, image_storage::ImageStorage,
             mission_management::MissionManagementRepository,
-            mission_viewing::MissionViewingRepository,
+            mission_template::MissionTemplateRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_model::{AddMissionModel, CloneMissionModel, EditMissionModel},
+            mission_revision_model::VersionConflict,
+            mission_role_model::SetRoleSlotsModel,
+            mission_template_model::AddMissionTemplateModel,
         },
-        value_objects::mission_model::{AddMissionModel, EditMissionModel},
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
+                activity::ActivityPostgres, guild::GuildPostgres,
                 mission_management::MissionManagementPostgres,
-                mission_viewing::MissionViewingPostgres,
+                mission_template::MissionTemplatePostgres, mission_viewing::MissionViewingPostgres,
             },
         },
         http::middlewares::auth::auth,
+        image_storage::ImageStorageBackend,
     },
 };
 
-pub async fn add<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+type MissionManagementState<T1, T2, T3, T4, T5, T6> =
+    State<Arc<MissionManagementUseCase<T1, T2, T3, T4, T5, T6>>>;
+
+pub async fn add<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
     Extension(user_id): Extension<i32>,
     Json(model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
 {
     match user_case.add(user_id, model).await {
         Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
@@ -46,35 +64,79 @@ where
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+pub async fn edit<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    headers: HeaderMap,
     Json(model): Json<EditMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
 {
-    match user_case.edit(mission_id, user_id, model).await {
-        Ok(mission_id) => (
+    let expected_version = match parse_if_match(&headers) {
+        Ok(version) => version,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+
+    match user_case
+        .edit(mission_id, user_id, expected_version, model)
+        .await
+    {
+        Ok(version) => (
             StatusCode::OK,
+            [(ETAG, format!("\"{}\"", version))],
             format!("Edit mission_id: {} completed!!", mission_id),
         )
             .into_response(),
 
+        Err(e) if e.is::<VersionConflict>() => {
+            (StatusCode::PRECONDITION_FAILED, e.to_string()).into_response()
+        }
+
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+/// `If-Match` carries the mission version as an entity tag, e.g. `"3"`; `*` matches any version.
+fn parse_if_match(headers: &HeaderMap) -> Result<Option<i32>, String> {
+    let Some(value) = headers.get(IF_MATCH) else {
+        return Ok(None);
+    };
+
+    let value = value
+        .to_str()
+        .map_err(|_| "Invalid If-Match header".to_string())?
+        .trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| "If-Match must be a mission version".to_string())
+}
+
+pub async fn remove<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
 {
     match user_case.remove(mission_id, user_id).await {
         Ok(_) => (
@@ -87,16 +149,228 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub async fn get_trash<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.get_trash(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn restore<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.restore(mission_id, user_id).await {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Restore mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn set_role_slots<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<SetRoleSlotsModel>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.set_role_slots(mission_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Set roles of mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn clone_mission<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<CloneMissionModel>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.clone_mission(mission_id, user_id, model).await {
+        Ok(new_mission_id) => (StatusCode::CREATED, new_mission_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn save_mission_as_template<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case
+        .save_mission_as_template(mission_id, user_id)
+        .await
+    {
+        Ok(template_id) => (StatusCode::CREATED, template_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn save_template<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.save_template(user_id, model).await {
+        Ok(template_id) => (StatusCode::CREATED, template_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_templates<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.get_templates(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove_template<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.remove_template(template_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove template_id: {} completed!!", template_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add_from_template<T1, T2, T3, T4, T5, T6>(
+    State(user_case): MissionManagementState<T1, T2, T3, T4, T5, T6>,
+    Extension(user_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+    T5: MissionTemplateRepository + Send + Sync,
+    T6: ImageStorage + Send + Sync,
+{
+    match user_case.add_from_template(template_id, user_id).await {
+        Ok(mission_id) => (StatusCode::CREATED, mission_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Router {
     let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let user_case =
-        MissionManagementUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
+    let guild_repository = GuildPostgres::new(Arc::clone(&db_pool));
+    let activity_repository = ActivityPostgres::new(Arc::clone(&db_pool));
+    let template_repository = MissionTemplatePostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionManagementUseCase::new(
+        Arc::new(mission_repository),
+        Arc::new(viewing_repositiory),
+        Arc::new(guild_repository),
+        Arc::new(activity_repository),
+        Arc::new(template_repository),
+        image_storage,
+    );
 
     Router::new()
         .route("/", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
-        .route_layer(middleware::from_fn(auth))
+        .route("/trash", get(get_trash))
+        .route("/{mission_id}/restore", post(restore))
+        .route("/{mission_id}/roles", put(set_role_slots))
+        .route("/{mission_id}/clone", post(clone_mission))
+        .route("/{mission_id}/template", post(save_mission_as_template))
+        .route("/templates", post(save_template).get(get_templates))
+        .route("/templates/{template_id}", delete(remove_template))
+        .route("/templates/{template_id}/missions", post(add_from_template))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post, put},
+};
+
+use crate::{
+    application::use_cases::mission_objective::MissionObjectiveUseCase,
+    domain::{
+        repositories::{
+            mission_objective::MissionObjectiveRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_objective_model::{
+            AddMissionObjectiveModel, EditMissionObjectiveModel, ReorderObjectivesModel,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_objective::MissionObjectivePostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn get_all<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_all(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.add(mission_id, user_id, model).await {
+        Ok(objective_id) => (StatusCode::CREATED, objective_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+    Json(model): Json<EditMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case
+        .edit(mission_id, objective_id, user_id, model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Edit objective_id: {} completed!!", objective_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.remove(mission_id, objective_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove objective_id: {} completed!!", objective_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn reorder<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<ReorderObjectivesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.reorder(mission_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Reorder objectives of mission_id: {} completed!!",
+                mission_id
+            ),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn complete<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.complete(mission_id, objective_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Complete objective_id: {} completed!!", objective_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn reopen<T1, T2>(
+    State(user_case): State<Arc<MissionObjectiveUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.reopen(mission_id, objective_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Reopen objective_id: {} completed!!", objective_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let objective_repository = MissionObjectivePostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionObjectiveUseCase::new(
+        Arc::new(objective_repository),
+        Arc::new(viewing_repositiory),
+    );
+
+    let protected_routes = Router::new()
+        .route("/{mission_id}", post(add))
+        .route("/{mission_id}/order", put(reorder))
+        .route("/{mission_id}/{objective_id}", patch(edit).delete(remove))
+        .route(
+            "/{mission_id}/{objective_id}/done",
+            post(complete).delete(reopen),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/{mission_id}", get(get_all))
+        .with_state(Arc::new(user_case))
+}
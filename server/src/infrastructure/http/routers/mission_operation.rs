@@ -1,75 +1,139 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
+    Extension, Json, Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::patch,
+    routing::{get, patch, post},
 };
 
 use crate::{
     application::use_cases::mission_operation::MissionOperationUseCase,
-    domain::repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, activity::ActivityRepository,
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository, points_ledger::PointsLedgerRepository,
+        },
+        value_objects::{
+            mission_outcome_model::AddOutcomeVoteModel, mission_statuses::StatusConflict,
+        },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
+                achievement::AchievementPostgres, activity::ActivityPostgres,
                 mission_operation::MissionOperationPostgres,
-                mission_viewing::MissionViewingPostgres,
+                mission_viewing::MissionViewingPostgres, points_ledger::PointsLedgerPostgres,
             },
         },
         http::middlewares::auth::auth,
     },
 };
 
-pub async fn in_progress<T1, T2>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+type MissionOperationState<T1, T2, T3, T4, T5> =
+    State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>;
+
+pub async fn in_progress<T1, T2, T3, T4, T5>(
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
 {
     match user_case.in_progress(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
+        Err(e) if e.is::<StatusConflict>() => (StatusCode::CONFLICT, e.to_string()).into_response(),
+
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub async fn to_completed<T1, T2>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_completed<T1, T2, T3, T4, T5>(
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
 {
     match user_case.to_completed(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
+        Err(e) if e.is::<StatusConflict>() => (StatusCode::CONFLICT, e.to_string()).into_response(),
+
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
 
-pub async fn to_failed<T1, T2>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+pub async fn to_failed<T1, T2, T3, T4, T5>(
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
 {
     match user_case.to_failed(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
+        Err(e) if e.is::<StatusConflict>() => (StatusCode::CONFLICT, e.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn vote<T1, T2, T3, T4, T5>(
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddOutcomeVoteModel>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
+{
+    match user_case.vote(mission_id, user_id, model).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_tally<T1, T2, T3, T4, T5>(
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: PointsLedgerRepository + Send + Sync,
+    T5: ActivityRepository + Send + Sync,
+{
+    match user_case.get_tally(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
 }
@@ -77,13 +141,26 @@ where
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let mission_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let user_case =
-        MissionOperationUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
+    let achievement_repository = AchievementPostgres::new(Arc::clone(&db_pool));
+    let points_ledger_repository = PointsLedgerPostgres::new(Arc::clone(&db_pool));
+    let activity_repository = ActivityPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionOperationUseCase::new(
+        Arc::new(mission_repository),
+        Arc::new(viewing_repositiory),
+        Arc::new(achievement_repository),
+        Arc::new(points_ledger_repository),
+        Arc::new(activity_repository),
+    );
 
-    Router::new()
+    let protected_routes = Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
-        .route_layer(middleware::from_fn(auth))
+        .route("/vote/{mission_id}", post(vote))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/votes/{mission_id}", get(get_tally))
         .with_state(Arc::new(user_case))
 }
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::mission_rating::MissionRatingUseCase,
+    domain::{
+        repositories::{
+            mission_rating::MissionRatingRepository, mission_viewing::MissionViewingRepository,
+            social::SocialRepository,
+        },
+        value_objects::mission_rating_model::AddMissionRatingModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_rating::MissionRatingPostgres, mission_viewing::MissionViewingPostgres,
+                social::SocialPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn rate<T1, T2, T3>(
+    State(user_case): State<Arc<MissionRatingUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionRatingModel>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: SocialRepository + Send + Sync,
+{
+    match user_case.rate(mission_id, user_id, model).await {
+        Ok(rating_id) => (StatusCode::CREATED, rating_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_received<T1, T2, T3>(
+    State(user_case): State<Arc<MissionRatingUseCase<T1, T2, T3>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: SocialRepository + Send + Sync,
+{
+    match user_case.get_received(brawler_id).await {
+        Ok(ratings) => (StatusCode::OK, Json(ratings)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_reputation<T1, T2, T3>(
+    State(user_case): State<Arc<MissionRatingUseCase<T1, T2, T3>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionRatingRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: SocialRepository + Send + Sync,
+{
+    match user_case.get_reputation(brawler_id).await {
+        Ok(reputation) => (StatusCode::OK, Json(reputation)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let rating_repository = MissionRatingPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let social_repository = SocialPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionRatingUseCase::new(
+        Arc::new(rating_repository),
+        Arc::new(viewing_repositiory),
+        Arc::new(social_repository),
+    );
+
+    let protected_routes = Router::new()
+        .route("/{mission_id}", post(rate))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/brawler/{brawler_id}", get(get_received))
+        .route("/reputation/{brawler_id}", get(get_reputation))
+        .with_state(Arc::new(user_case))
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    domain::{
+        repositories::{
+            activity::ActivityRepository, guild::GuildRepository,
+            mission_series::MissionSeriesRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_series_model::{AddMissionSeriesModel, EditMissionSeriesModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                activity::ActivityPostgres, guild::GuildPostgres,
+                mission_series::MissionSeriesPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+type MissionSeriesState<T1, T2, T3, T4> = State<Arc<MissionSeriesUseCase<T1, T2, T3, T4>>>;
+
+pub async fn create<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.create(user_id, model).await {
+        Ok(series_id) => (StatusCode::CREATED, series_id.to_string()).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_mine<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.get_mine(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_one<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.get_one(series_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_occurrences<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.get_occurrences(series_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn edit<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Extension(user_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+    Json(model): Json<EditMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.edit(series_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Edit series_id: {} completed!!", series_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn cancel<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Extension(user_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.cancel(series_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Cancel series_id: {} completed!!", series_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn skip_next<T1, T2, T3, T4>(
+    State(user_case): MissionSeriesState<T1, T2, T3, T4>,
+    Extension(user_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: GuildRepository + Send + Sync,
+    T4: ActivityRepository + Send + Sync,
+{
+    match user_case.skip_next(series_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Skip next occurrence of series_id: {} completed!!",
+                series_id
+            ),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let series_repository = MissionSeriesPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let guild_repository = GuildPostgres::new(Arc::clone(&db_pool));
+    let activity_repository = ActivityPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionSeriesUseCase::new(
+        Arc::new(series_repository),
+        Arc::new(viewing_repositiory),
+        Arc::new(guild_repository),
+        Arc::new(activity_repository),
+    );
+
+    let protected_routes = Router::new()
+        .route("/", post(create))
+        .route("/mine", get(get_mine))
+        .route("/{series_id}", patch(edit).delete(cancel))
+        .route("/{series_id}/skip", post(skip_next))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/{series_id}", get(get_one))
+        .route("/{series_id}/occurrences", get(get_occurrences))
+        .with_state(Arc::new(user_case))
+}
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{StatusCode, header::ETAG},
+    middleware,
     response::IntoResponse,
     routing::get,
 };
@@ -12,10 +13,17 @@ use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
+        value_objects::{
+            mission_filter::{AutocompleteFilter, MissionFilter, RecommendationFilter},
+            mission_revision_model::{RevisionDiffFilter, RevisionNotFound},
+        },
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_viewing::MissionViewingPostgres,
+        },
+        http::middlewares::auth::auth,
     },
 };
 
@@ -27,7 +35,12 @@ where
     T: MissionViewingRepository + Send + Sync,
 {
     match user_case.get_one(mission_id).await {
-        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+        Ok(model) => (
+            StatusCode::OK,
+            [(ETAG, format!("\"{}\"", model.version))],
+            Json(model),
+        )
+            .into_response(),
 
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -47,6 +60,20 @@ where
     }
 }
 
+pub async fn get_role_slots<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_role_slots(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub async fn get_all<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
     filter: Query<MissionFilter>,
@@ -61,14 +88,100 @@ where
     }
 }
 
+pub async fn autocomplete<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    filter: Query<AutocompleteFilter>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.autocomplete(&filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_followed<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_followed(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_recommended<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    filter: Query<RecommendationFilter>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_recommended(user_id, &filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_revisions<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_revisions(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_revision_diff<T>(
+    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+    filter: Query<RevisionDiffFilter>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_revision_diff(mission_id, &filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) if e.is::<RevisionNotFound>() => {
+            (StatusCode::NOT_FOUND, e.to_string()).into_response()
+        }
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let user_case = MissionViewingUseCase::new(Arc::new(viewing_repositiory));
 
+    let protected_routes = Router::new()
+        .route("/friends", get(get_followed))
+        .route("/recommended", get(get_recommended))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
     Router::new()
+        .merge(protected_routes)
         .route("/{mission_id}", get(get_one))
         .route("/filter", get(get_all))
+        .route("/autocomplete", get(autocomplete))
         .route("/crew/{mission_id}", get(get_crew))
-        // .route_layer(middleware::from_fn(auth))
+        .route("/roles/{mission_id}", get(get_role_slots))
+        .route("/revisions/{mission_id}", get(get_revisions))
+        .route("/revisions/{mission_id}/diff", get(get_revision_diff))
+        // .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
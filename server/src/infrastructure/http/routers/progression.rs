@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::progression::ProgressionUseCase,
+    domain::{
+        repositories::points_ledger::PointsLedgerRepository,
+        value_objects::progression_model::{PointsCorrectionModel, PointsHistoryFilter},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::points_ledger::PointsLedgerPostgres,
+        },
+        http::middlewares::auth::{admin, auth},
+    },
+};
+
+pub async fn get_mine<T>(
+    State(user_case): State<Arc<ProgressionUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    match user_case.get_progression(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_history<T>(
+    State(user_case): State<Arc<ProgressionUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    filter: Query<PointsHistoryFilter>,
+) -> impl IntoResponse
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    match user_case.get_history(user_id, &filter).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_progression<T>(
+    State(user_case): State<Arc<ProgressionUseCase<T>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    match user_case.get_progression(brawler_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn issue_correction<T>(
+    State(user_case): State<Arc<ProgressionUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<PointsCorrectionModel>,
+) -> impl IntoResponse
+where
+    T: PointsLedgerRepository + Send + Sync,
+{
+    match user_case.issue_correction(user_id, model).await {
+        Ok(_) => (StatusCode::CREATED, "Correction issued").into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let points_ledger_repository = PointsLedgerPostgres::new(Arc::clone(&db_pool));
+    let user_case = ProgressionUseCase::new(Arc::new(points_ledger_repository));
+
+    let protected_routes = Router::new()
+        .route("/me", get(get_mine))
+        .route("/history", get(get_history))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth));
+
+    let admin_routes = Router::new()
+        .route("/corrections", post(issue_correction))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), admin));
+
+    Router::new()
+        .merge(protected_routes)
+        .merge(admin_routes)
+        .route("/brawler/{brawler_id}", get(get_progression))
+        .with_state(Arc::new(user_case))
+}
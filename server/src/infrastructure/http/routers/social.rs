@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::social::SocialUseCase,
+    domain::repositories::social::SocialRepository,
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::social::SocialPostgres},
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn follow<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.follow(user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Follow brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn unfollow<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.unfollow(user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Unfollow brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_following<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.get_following(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_followers<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.get_followers(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_friends<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.get_friends(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn block<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.block(user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Block brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn unblock<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.unblock(user_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Unblock brawler_id:{} completed", brawler_id),
+        )
+            .into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn get_blocked<T>(
+    State(user_case): State<Arc<SocialUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: SocialRepository + Send + Sync,
+{
+    match user_case.get_blocked(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let social_repository = SocialPostgres::new(Arc::clone(&db_pool));
+    let user_case = SocialUseCase::new(Arc::new(social_repository));
+
+    Router::new()
+        .route("/follow/{brawler_id}", post(follow).delete(unfollow))
+        .route("/following", get(get_following))
+        .route("/followers", get(get_followers))
+        .route("/friends", get(get_friends))
+        .route("/block/{brawler_id}", post(block).delete(unblock))
+        .route("/blocked", get(get_blocked))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
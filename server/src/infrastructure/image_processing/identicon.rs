@@ -0,0 +1,41 @@
+use sha2::{Digest, Sha256};
+
+const GRID: usize = 5;
+const CELL: usize = 40;
+const PADDING: usize = 20;
+
+/// A GitHub-style identicon: a 5x5 grid mirrored around its middle column, coloured
+/// and filled from a hash of the brawler id, so the same id always gets the same image.
+pub fn identicon_svg(brawler_id: i32) -> String {
+    let hash = Sha256::digest(format!("brawler:{}", brawler_id).as_bytes());
+
+    let hue = u16::from_be_bytes([hash[0], hash[1]]) % 360;
+    let color = format!("hsl({}, 55%, 50%)", hue);
+
+    let side = GRID * CELL + PADDING * 2;
+    let mut cells = String::new();
+    for row in 0..GRID {
+        for col in 0..GRID.div_ceil(2) {
+            // One bit per cell of the left half, starting after the colour bytes.
+            let bit = row * GRID.div_ceil(2) + col;
+            if hash[2 + bit / 8] >> (bit % 8) & 1 == 0 {
+                continue;
+            }
+
+            for x in [col, GRID - 1 - col] {
+                cells.push_str(&format!(
+                    r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}"/>"#,
+                    PADDING + x * CELL,
+                    PADDING + row * CELL,
+                ));
+                if x == GRID / 2 {
+                    break;
+                }
+            }
+        }
+    }
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{side}" height="{side}" viewBox="0 0 {side} {side}"><rect width="{side}" height="{side}" fill="#f0f0f0"/><g fill="{color}">{cells}</g></svg>"##
+    )
+}
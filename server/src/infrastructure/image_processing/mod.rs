@@ -0,0 +1,182 @@
+pub mod identicon;
+
+use std::io::Cursor;
+
+use anyhow::Result;
+use image::{
+    DynamicImage, ImageDecoder, ImageReader, Limits, codecs::webp::WebPEncoder,
+    imageops::FilterType,
+};
+
+use crate::domain::value_objects::upload_file::UploadFile;
+
+/// Edge lengths of the square avatar thumbnails, largest first.
+pub const AVATAR_SIZES: [u32; 3] = [256, 128, 64];
+const MIN_DIMENSION: u32 = 32;
+const MAX_DIMENSION: u32 = 8192;
+const MAX_DECODE_BYTES: u64 = 256 * 1024 * 1024;
+const OUTPUT_MIME_TYPES: [&str; 1] = ["image/webp"];
+
+pub struct AvatarVariant {
+    pub size: u32,
+    pub file: UploadFile,
+}
+
+/// Decodes an uploaded image and re-encodes it as square WebP thumbnails, one per
+/// entry of `AVATAR_SIZES`. Re-encoding drops EXIF and every other metadata block,
+/// after the EXIF orientation has been applied to the pixels.
+///
+/// CPU heavy, so call it from a blocking task.
+pub fn avatar_variants(file: &UploadFile) -> Result<Vec<AvatarVariant>> {
+    let image = decode(file)?;
+
+    let side = image.width().min(image.height());
+    let square = image.crop_imm(
+        (image.width() - side) / 2,
+        (image.height() - side) / 2,
+        side,
+        side,
+    );
+
+    AVATAR_SIZES
+        .iter()
+        .map(|&size| {
+            let thumbnail = square.resize_exact(size, size, FilterType::Lanczos3);
+            Ok(AvatarVariant {
+                size,
+                file: encode_webp(&thumbnail)?,
+            })
+        })
+        .collect()
+}
+
+fn decode(file: &UploadFile) -> Result<DynamicImage> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+
+    let mut reader = ImageReader::new(Cursor::new(file.bytes())).with_guessed_format()?;
+    reader.limits(limits);
+
+    let mut decoder = reader
+        .into_decoder()
+        .map_err(|e| anyhow::anyhow!("can not read image: {}", e))?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)
+        .map_err(|e| anyhow::anyhow!("can not read image: {}", e))?;
+    image.apply_orientation(orientation);
+
+    if image.width() < MIN_DIMENSION || image.height() < MIN_DIMENSION {
+        return Err(anyhow::anyhow!(
+            "image must be at least {}x{} pixels",
+            MIN_DIMENSION,
+            MIN_DIMENSION
+        ));
+    }
+
+    Ok(image)
+}
+
+fn encode_webp(image: &DynamicImage) -> Result<UploadFile> {
+    // The WebP encoder only takes 8-bit RGB(A).
+    let image = DynamicImage::ImageRgba8(image.to_rgba8());
+
+    let mut bytes = Vec::new();
+    image.write_with_encoder(WebPEncoder::new_lossless(&mut bytes))?;
+
+    UploadFile::new(bytes, &OUTPUT_MIME_TYPES)
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{ImageFormat, Rgb, RgbImage, codecs::jpeg::JpegEncoder};
+
+    use super::*;
+
+    const RED: Rgb<u8> = Rgb([255, 0, 0]);
+    const BLUE: Rgb<u8> = Rgb([0, 0, 255]);
+
+    /// A JPEG that is red on top and blue below, optionally carrying an EXIF
+    /// orientation tag.
+    fn jpeg(width: u32, height: u32, orientation: Option<u16>) -> UploadFile {
+        let image = RgbImage::from_fn(
+            width,
+            height,
+            |_, y| if y < height / 2 { RED } else { BLUE },
+        );
+        let mut bytes = Vec::new();
+        image
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, 95))
+            .unwrap();
+
+        if let Some(orientation) = orientation {
+            // Big-endian TIFF header with a single IFD entry: Orientation (0x0112), SHORT.
+            let mut exif = b"Exif\0\0MM\0\x2a\0\0\0\x08\0\x01\x01\x12\0\x03\0\0\0\x01".to_vec();
+            exif.extend_from_slice(&orientation.to_be_bytes());
+            exif.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+
+            let mut segment = vec![0xff, 0xe1];
+            segment.extend_from_slice(&(exif.len() as u16 + 2).to_be_bytes());
+            segment.extend_from_slice(&exif);
+            bytes.splice(2..2, segment);
+        }
+
+        UploadFile::new(bytes, &["image/jpeg"]).unwrap()
+    }
+
+    fn is_red(pixel: &image::Rgba<u8>) -> bool {
+        pixel[0] > 200 && pixel[2] < 60
+    }
+
+    fn is_blue(pixel: &image::Rgba<u8>) -> bool {
+        pixel[2] > 200 && pixel[0] < 60
+    }
+
+    #[test]
+    fn decode_applies_exif_orientation() {
+        let upright = decode(&jpeg(64, 48, None)).unwrap();
+        assert_eq!((upright.width(), upright.height()), (64, 48));
+
+        // 6 means the stored pixels must be turned 90 degrees clockwise,
+        // which moves the red top half to the right.
+        let rotated = decode(&jpeg(64, 48, Some(6))).unwrap().to_rgba8();
+        assert_eq!((rotated.width(), rotated.height()), (48, 64));
+        assert!(is_blue(rotated.get_pixel(4, 32)));
+        assert!(is_red(rotated.get_pixel(43, 32)));
+    }
+
+    #[test]
+    fn avatar_variants_are_square_webp_without_exif() {
+        let variants = avatar_variants(&jpeg(300, 200, Some(6))).unwrap();
+
+        let sizes: Vec<u32> = variants.iter().map(|variant| variant.size).collect();
+        assert_eq!(sizes, AVATAR_SIZES);
+
+        for variant in variants.iter() {
+            let bytes = variant.file.bytes();
+            assert_eq!(variant.file.mime_type(), "image/webp");
+            assert!(
+                !bytes
+                    .windows(4)
+                    .any(|chunk| chunk == b"EXIF" || chunk == b"Exif"),
+                "{}px variant carries EXIF",
+                variant.size
+            );
+
+            let image = image::load_from_memory_with_format(bytes, ImageFormat::WebP).unwrap();
+            assert_eq!(
+                (image.width(), image.height()),
+                (variant.size, variant.size)
+            );
+        }
+    }
+
+    #[test]
+    fn decode_rejects_images_under_min_dimension() {
+        let error = decode(&jpeg(64, MIN_DIMENSION - 1, None)).err().unwrap();
+        assert!(error.to_string().contains("at least"));
+
+        assert!(decode(&jpeg(MIN_DIMENSION, MIN_DIMENSION, None)).is_ok());
+    }
+}
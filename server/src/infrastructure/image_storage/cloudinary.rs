@@ -0,0 +1,251 @@
+use crate::{
+    config::config_model::CloudinaryEnv,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            upload_file::UploadFile,
+            uploaded_img::{UploadImageOptions, UploadedImg},
+            upstream_error::UpstreamError,
+        },
+    },
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+use std::{collections::BTreeMap, time::Duration};
+use tracing::warn;
+
+const SERVICE: &str = "Cloudinary";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_BACKOFF: Duration = Duration::from_millis(250);
+/// Sent with a request but never part of its signature.
+const UNSIGNED_PARAMS: [&str; 4] = ["file", "api_key", "resource_type", "cloud_name"];
+
+#[derive(Deserialize)]
+struct DestroyResult {
+    result: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorMessage,
+}
+
+#[derive(Deserialize)]
+struct ErrorMessage {
+    message: String,
+}
+
+pub struct CloudinaryStorage {
+    cloud_env: CloudinaryEnv,
+    client: reqwest::Client,
+}
+
+impl CloudinaryStorage {
+    pub fn new(cloud_env: CloudinaryEnv) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self { cloud_env, client })
+    }
+
+    fn endpoint(&self, action: &str) -> String {
+        format!(
+            "{}/{}/image/{}",
+            self.cloud_env.base_url.trim_end_matches('/'),
+            self.cloud_env.cloud_name,
+            action
+        )
+    }
+
+    /// Adds the timestamp, signature and api key every signed call needs.
+    fn signed_params(
+        &self,
+        mut params: BTreeMap<&'static str, String>,
+    ) -> BTreeMap<&'static str, String> {
+        // Cloudinary wants a unix timestamp in seconds.
+        params.insert("timestamp", Utc::now().timestamp().to_string());
+        let signature = sign(&params, &self.cloud_env.api_secret);
+        params.insert("signature", signature);
+        params.insert("api_key", self.cloud_env.api_key.clone());
+        params
+    }
+
+    /// Posts the form built by `form`, retrying with backoff on 5xx responses and
+    /// connection failures. Any other error response becomes an `UpstreamError`.
+    async fn post(&self, action: &str, form: impl Fn() -> Result<Form>) -> Result<String> {
+        let url = self.endpoint(action);
+
+        let mut attempt = 1;
+        loop {
+            let result = self.client.post(&url).multipart(form()?).send().await;
+
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => e.is_timeout() || e.is_connect(),
+            };
+            if retryable && attempt < MAX_ATTEMPTS {
+                warn!(
+                    "{} {} failed on attempt {}, retrying",
+                    SERVICE, action, attempt
+                );
+                tokio::time::sleep(RETRY_BACKOFF * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+                continue;
+            }
+
+            let response = result.context(format!("{} on {}", action, url))?;
+            let status = response.status();
+            let text = response.text().await?;
+            if !status.is_success() {
+                let message = serde_json::from_str::<ErrorResponse>(&text)
+                    .map(|body| body.error.message)
+                    .unwrap_or(text);
+                return Err(UpstreamError {
+                    service: SERVICE,
+                    status: status.as_u16(),
+                    message,
+                }
+                .into());
+            }
+            return Ok(text);
+        }
+    }
+}
+
+/// Cloudinary's request signature: the signed parameters sorted by name, joined as
+/// `key=value&key=value`, followed by the API secret and hashed with SHA-1.
+fn sign(params: &BTreeMap<&str, String>, api_secret: &str) -> String {
+    let to_sign = params
+        .iter()
+        .filter(|(key, _)| !UNSIGNED_PARAMS.contains(key))
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    let mut hasher = Sha1::new();
+    hasher.update(to_sign);
+    hasher.update(api_secret);
+    format!("{:x}", hasher.finalize())
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryStorage {
+    async fn upload(
+        &self,
+        upload_file: UploadFile,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImg> {
+        let mut params = BTreeMap::new();
+        if let Some(folder_name) = option.folder {
+            params.insert("folder", folder_name);
+        }
+        if let Some(public_id) = option.public_id {
+            params.insert("public_id", public_id);
+        }
+        if let Some(transformation) = option.transformation {
+            params.insert("transformation", transformation);
+        }
+        let params = self.signed_params(params);
+
+        let file_name = format!("upload.{}", upload_file.extension());
+        let mime_type = upload_file.mime_type();
+        let bytes = upload_file.into_bytes();
+
+        let text = self
+            .post("upload", || {
+                let file = Part::bytes(bytes.clone())
+                    .file_name(file_name.clone())
+                    .mime_str(mime_type)?;
+                let form = params
+                    .iter()
+                    .fold(Form::new(), |form, (key, value)| {
+                        form.text(*key, value.clone())
+                    })
+                    .part("file", file);
+                Ok(form)
+            })
+            .await?;
+
+        let json: UploadedImg =
+            serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+        Ok(json)
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        let params = self.signed_params(BTreeMap::from([("public_id", public_id.to_string())]));
+
+        let text = self
+            .post("destroy", || {
+                let form = params.iter().fold(Form::new(), |form, (key, value)| {
+                    form.text(*key, value.clone())
+                });
+                Ok(form)
+            })
+            .await?;
+
+        let json: DestroyResult =
+            serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+        // "not found" means the file is already gone, which is what we wanted.
+        if json.result != "ok" && json.result != "not found" {
+            return Err(anyhow::anyhow!(
+                "failed to destroy {}: {}",
+                public_id,
+                json.result
+            ));
+        }
+        Ok(())
+    }
+
+    fn public_url(&self, public_id: &str) -> String {
+        format!(
+            "https://res.cloudinary.com/{}/image/upload/{}",
+            self.cloud_env.cloud_name, public_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://cloudinary.com/documentation/authentication_signatures
+    #[test]
+    fn sign_matches_documented_example() {
+        let params = BTreeMap::from([
+            ("timestamp", "1315060510".to_string()),
+            ("public_id", "sample_image".to_string()),
+            ("eager", "w_400,h_300,c_pad|w_260,h_200,c_crop".to_string()),
+        ]);
+
+        assert_eq!(
+            sign(&params, "abcd"),
+            "bfd09f95f331f558cbd1320e67aa8d488770583e"
+        );
+    }
+
+    #[test]
+    fn sign_skips_unsigned_params() {
+        let params = BTreeMap::from([
+            ("timestamp", "1315060510".to_string()),
+            ("public_id", "sample_image".to_string()),
+            ("eager", "w_400,h_300,c_pad|w_260,h_200,c_crop".to_string()),
+            ("api_key", "1234".to_string()),
+            ("resource_type", "image".to_string()),
+            ("cloud_name", "demo".to_string()),
+            ("file", "data:image/png;base64,AAAA".to_string()),
+        ]);
+
+        assert_eq!(
+            sign(&params, "abcd"),
+            "bfd09f95f331f558cbd1320e67aa8d488770583e"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::{io::ErrorKind, path::PathBuf};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    config::config_model::LocalStorageEnv,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            upload_file::UploadFile,
+            uploaded_img::{UploadImageOptions, UploadedImg},
+        },
+    },
+};
+
+use super::{check_key, object_key};
+
+/// Keeps files on disk. Point `dir` inside `statics/` so the static layer serves them.
+pub struct LocalStorage {
+    dir: PathBuf,
+    public_url: String,
+}
+
+impl LocalStorage {
+    pub fn new(local_env: LocalStorageEnv) -> Self {
+        Self {
+            dir: PathBuf::from(local_env.dir),
+            public_url: local_env.public_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for LocalStorage {
+    async fn upload(&self, file: UploadFile, opt: UploadImageOptions) -> Result<UploadedImg> {
+        let key = object_key(&file, &opt)?;
+        let path = self.dir.join(&key);
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, file.into_bytes()).await?;
+
+        Ok(UploadedImg::new(self.public_url(&key), key))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        check_key(public_id)?;
+
+        match tokio::fs::remove_file(self.dir.join(public_id)).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn public_url(&self, public_id: &str) -> String {
+        format!("{}/{}", self.public_url, public_id)
+    }
+}
@@ -0,0 +1,94 @@
+pub mod cloudinary;
+pub mod local;
+pub mod s3;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    config::{config_loader::get_image_storage_env, config_model::ImageStorageEnv},
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            upload_file::UploadFile,
+            uploaded_img::{UploadImageOptions, UploadedImg},
+        },
+    },
+};
+
+use self::{cloudinary::CloudinaryStorage, local::LocalStorage, s3::S3Storage};
+
+/// The image store picked by `IMAGE_STORAGE_BACKEND`.
+pub enum ImageStorageBackend {
+    Cloudinary(CloudinaryStorage),
+    Local(LocalStorage),
+    S3(S3Storage),
+}
+
+impl ImageStorageBackend {
+    pub fn new(image_storage_env: ImageStorageEnv) -> Result<Self> {
+        let backend = match image_storage_env {
+            ImageStorageEnv::Cloudinary(env) => Self::Cloudinary(CloudinaryStorage::new(env)?),
+            ImageStorageEnv::Local(env) => Self::Local(LocalStorage::new(env)),
+            ImageStorageEnv::S3(env) => Self::S3(S3Storage::new(env)?),
+        };
+        Ok(backend)
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Self::new(get_image_storage_env()?)
+    }
+}
+
+#[async_trait]
+impl ImageStorage for ImageStorageBackend {
+    async fn upload(&self, file: UploadFile, opt: UploadImageOptions) -> Result<UploadedImg> {
+        match self {
+            Self::Cloudinary(storage) => storage.upload(file, opt).await,
+            Self::Local(storage) => storage.upload(file, opt).await,
+            Self::S3(storage) => storage.upload(file, opt).await,
+        }
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        match self {
+            Self::Cloudinary(storage) => storage.delete(public_id).await,
+            Self::Local(storage) => storage.delete(public_id).await,
+            Self::S3(storage) => storage.delete(public_id).await,
+        }
+    }
+
+    fn public_url(&self, public_id: &str) -> String {
+        match self {
+            Self::Cloudinary(storage) => storage.public_url(public_id),
+            Self::Local(storage) => storage.public_url(public_id),
+            Self::S3(storage) => storage.public_url(public_id),
+        }
+    }
+}
+
+/// Key of a stored file for the stores that keep plain objects, e.g. `avatar/7.png`.
+/// The key doubles as the public id.
+fn object_key(file: &UploadFile, opt: &UploadImageOptions) -> Result<String> {
+    let name = opt
+        .public_id
+        .clone()
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let key = match &opt.folder {
+        Some(folder) => format!("{}/{}.{}", folder, name, file.extension()),
+        None => format!("{}.{}", name, file.extension()),
+    };
+    check_key(&key)?;
+    Ok(key)
+}
+
+fn check_key(key: &str) -> Result<()> {
+    let is_valid = !key.is_empty()
+        && key
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != "..");
+    if !is_valid {
+        return Err(anyhow::anyhow!("invalid file key: {}", key));
+    }
+    Ok(())
+}
@@ -0,0 +1,307 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Method, StatusCode, Url};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    config::config_model::S3Env,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            upload_file::UploadFile,
+            uploaded_img::{UploadImageOptions, UploadedImg},
+        },
+    },
+};
+
+use super::{check_key, object_key};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Any S3-compatible store (AWS, MinIO, R2, ...), addressed path-style
+/// as `{endpoint}/{bucket}/{key}` and signed with SigV4.
+pub struct S3Storage {
+    s3_env: S3Env,
+    host: String,
+    client: reqwest::Client,
+}
+
+impl S3Storage {
+    pub fn new(s3_env: S3Env) -> Result<Self> {
+        let endpoint = Url::parse(&s3_env.endpoint).context("invalid S3_ENDPOINT")?;
+        let host = match (endpoint.host_str(), endpoint.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            _ => return Err(anyhow::anyhow!("S3_ENDPOINT has no host")),
+        };
+
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            s3_env,
+            host,
+            client,
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<StatusCode> {
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = format!("{:x}", Sha256::digest(&body));
+
+        let canonical_uri = format!("/{}/{}", self.s3_env.bucket, uri_encode(key));
+        let headers = [
+            ("host", self.host.as_str()),
+            ("x-amz-content-sha256", payload_hash.as_str()),
+            ("x-amz-date", amz_date.as_str()),
+        ];
+        let canonical_request = canonical_request(&method, &canonical_uri, &headers, &payload_hash);
+
+        let scope = format!("{}/{}/s3/aws4_request", date, self.s3_env.region);
+        let signature = signature(
+            &self.s3_env.secret_key,
+            &date,
+            &self.s3_env.region,
+            &string_to_sign(&amz_date, &scope, &canonical_request),
+        )?;
+
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.s3_env.access_key,
+            scope,
+            signed_headers(&headers),
+            signature
+        );
+
+        let url = format!(
+            "{}{}",
+            self.s3_env.endpoint.trim_end_matches('/'),
+            canonical_uri
+        );
+        let mut request = self
+            .client
+            .request(method, &url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("authorization", authorization)
+            .body(body);
+        if let Some(content_type) = content_type {
+            request = request.header("content-type", content_type);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context(format!("request to {}", url))?;
+        let status = response.status();
+        if !status.is_success() && status != StatusCode::NOT_FOUND {
+            let text = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("{} on {}: {}", status, url, text));
+        }
+        Ok(status)
+    }
+}
+
+#[async_trait]
+impl ImageStorage for S3Storage {
+    async fn upload(&self, file: UploadFile, opt: UploadImageOptions) -> Result<UploadedImg> {
+        let key = object_key(&file, &opt)?;
+        let mime_type = file.mime_type();
+
+        let status = self
+            .send(Method::PUT, &key, file.into_bytes(), Some(mime_type))
+            .await?;
+        if status == StatusCode::NOT_FOUND {
+            return Err(anyhow::anyhow!("bucket {} not found", self.s3_env.bucket));
+        }
+
+        Ok(UploadedImg::new(self.public_url(&key), key))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        check_key(public_id)?;
+
+        self.send(Method::DELETE, public_id, Vec::new(), None)
+            .await?;
+        Ok(())
+    }
+
+    fn public_url(&self, public_id: &str) -> String {
+        match &self.s3_env.public_url {
+            Some(public_url) => format!("{}/{}", public_url.trim_end_matches('/'), public_id),
+            None => format!(
+                "{}/{}/{}",
+                self.s3_env.endpoint.trim_end_matches('/'),
+                self.s3_env.bucket,
+                public_id
+            ),
+        }
+    }
+}
+
+/// `headers` are lowercase and sorted by name; requests carry no query string.
+fn canonical_request(
+    method: &Method,
+    canonical_uri: &str,
+    headers: &[(&str, &str)],
+    payload_hash: &str,
+) -> String {
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .collect();
+    format!(
+        "{}\n{}\n\n{}\n{}\n{}",
+        method,
+        canonical_uri,
+        canonical_headers,
+        signed_headers(headers),
+        payload_hash
+    )
+}
+
+fn signed_headers(headers: &[(&str, &str)]) -> String {
+    headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn string_to_sign(amz_date: &str, scope: &str, canonical_request: &str) -> String {
+    format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{:x}",
+        amz_date,
+        scope,
+        Sha256::digest(canonical_request.as_bytes())
+    )
+}
+
+fn signature(secret_key: &str, date: &str, region: &str, string_to_sign: &str) -> Result<String> {
+    let secret = format!("AWS4{}", secret_key);
+    let signing_key = [date, region, "s3", "aws4_request"]
+        .iter()
+        .try_fold(secret.into_bytes(), |key, part| hmac_sha256(&key, part))?;
+    Ok(hex(&hmac_sha256(&signing_key, string_to_sign)?))
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Result<Vec<u8>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)?;
+    mac.update(data.as_bytes());
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Percent-encodes a key the way SigV4 expects, keeping `/` between segments.
+fn uri_encode(key: &str) -> String {
+    key.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET_KEY: &str = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+    const AMZ_DATE: &str = "20130524T000000Z";
+    const SCOPE: &str = "20130524/us-east-1/s3/aws4_request";
+    const EMPTY_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    // https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-header-based-auth.html
+    #[test]
+    fn signs_documented_get_object() {
+        let headers = [
+            ("host", "examplebucket.s3.amazonaws.com"),
+            ("range", "bytes=0-9"),
+            ("x-amz-content-sha256", EMPTY_HASH),
+            ("x-amz-date", AMZ_DATE),
+        ];
+        let canonical_request = canonical_request(&Method::GET, "/test.txt", &headers, EMPTY_HASH);
+        assert_eq!(
+            canonical_request,
+            "GET\n/test.txt\n\n\
+             host:examplebucket.s3.amazonaws.com\n\
+             range:bytes=0-9\n\
+             x-amz-content-sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855\n\
+             x-amz-date:20130524T000000Z\n\n\
+             host;range;x-amz-content-sha256;x-amz-date\n\
+             e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let string_to_sign = string_to_sign(AMZ_DATE, SCOPE, &canonical_request);
+        assert_eq!(
+            string_to_sign,
+            "AWS4-HMAC-SHA256\n20130524T000000Z\n20130524/us-east-1/s3/aws4_request\n\
+             7344ae5b7ee6c3e7e6b0fe0640412a37625d1fbfff95c48bbb2dc43964946972"
+        );
+
+        assert_eq!(
+            signature(SECRET_KEY, "20130524", "us-east-1", &string_to_sign).unwrap(),
+            "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb41"
+        );
+    }
+
+    #[test]
+    fn signs_documented_put_object() {
+        let payload_hash = format!("{:x}", Sha256::digest(b"Welcome to Amazon S3."));
+        assert_eq!(
+            payload_hash,
+            "44ce7dd67c959e0d3524ffac1771dfbba87d2b6b4b4e99e42034a8b803f8b072"
+        );
+
+        let canonical_uri = format!("/{}", uri_encode("test$file.text"));
+        let headers = [
+            ("date", "Fri, 24 May 2013 00:00:00 GMT"),
+            ("host", "examplebucket.s3.amazonaws.com"),
+            ("x-amz-content-sha256", payload_hash.as_str()),
+            ("x-amz-date", AMZ_DATE),
+            ("x-amz-storage-class", "REDUCED_REDUNDANCY"),
+        ];
+        let canonical_request =
+            canonical_request(&Method::PUT, &canonical_uri, &headers, &payload_hash);
+        let string_to_sign = string_to_sign(AMZ_DATE, SCOPE, &canonical_request);
+        assert_eq!(
+            string_to_sign,
+            "AWS4-HMAC-SHA256\n20130524T000000Z\n20130524/us-east-1/s3/aws4_request\n\
+             9e0e90d9c76de8fa5b200d8c849cd5b8dc7a3be3951ddb7f6a76b4158342019d"
+        );
+
+        assert_eq!(
+            signature(SECRET_KEY, "20130524", "us-east-1", &string_to_sign).unwrap(),
+            "98ad721746da40c64f1a55b78f14c238d841ea1380cd77a1b5971af0ece108bd"
+        );
+    }
+
+    #[test]
+    fn uri_encode_keeps_slashes_and_unreserved() {
+        assert_eq!(
+            uri_encode("mission_banner/12_ab-c.~x.webp"),
+            "mission_banner/12_ab-c.~x.webp"
+        );
+        assert_eq!(uri_encode("a b+c$d=é"), "a%20b%2Bc%24d%3D%C3%A9");
+    }
+}
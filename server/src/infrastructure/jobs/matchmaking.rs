@@ -0,0 +1,73 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::{
+        crew_operation::CrewOperationUseCase, matchmaking::MatchmakingUseCase,
+        mission_management::MissionManagementUseCase,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievement::AchievementPostgres, activity::ActivityPostgres,
+                crew_operation::CrewOperationPostgres, guild::GuildPostgres,
+                matchmaking::MatchmakingPostgres, mission_management::MissionManagementPostgres,
+                mission_rating::MissionRatingPostgres, mission_template::MissionTemplatePostgres,
+                mission_viewing::MissionViewingPostgres, social::SocialPostgres,
+            },
+        },
+        image_storage::ImageStorageBackend,
+    },
+};
+
+pub async fn run(
+    db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<ImageStorageBackend>,
+    period: Duration,
+) {
+    let viewing_repository = Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool)));
+    let rating_repository = Arc::new(MissionRatingPostgres::new(Arc::clone(&db_pool)));
+    let activity_repository = Arc::new(ActivityPostgres::new(Arc::clone(&db_pool)));
+    let guild_repository = Arc::new(GuildPostgres::new(Arc::clone(&db_pool)));
+    let crew_operation_user_case = CrewOperationUseCase::new(
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&viewing_repository),
+        Arc::clone(&rating_repository),
+        Arc::new(AchievementPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&guild_repository),
+        Arc::new(SocialPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&activity_repository),
+    );
+    let management_user_case = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&viewing_repository),
+        guild_repository,
+        activity_repository,
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        image_storage,
+    );
+    let user_case = MatchmakingUseCase::new(
+        Arc::new(crew_operation_user_case),
+        Arc::new(management_user_case),
+        viewing_repository,
+        rating_repository,
+        Arc::new(MatchmakingPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let mut ticker = interval(period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        match user_case.match_queued(Utc::now().naive_utc()).await {
+            Ok(0) => {}
+            Ok(proposed) => info!("Sent {} matchmaking proposals", proposed),
+            Err(e) => error!("Failed to run matchmaking: {}", e),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            activity::ActivityPostgres, guild::GuildPostgres,
+            mission_series::MissionSeriesPostgres, mission_viewing::MissionViewingPostgres,
+        },
+    },
+};
+
+pub async fn run(db_pool: Arc<PgPoolSquad>, period: Duration) {
+    let user_case = MissionSeriesUseCase::new(
+        Arc::new(MissionSeriesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(GuildPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(ActivityPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let mut ticker = interval(period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        match user_case.materialize_due(Utc::now().naive_utc()).await {
+            Ok(0) => {}
+            Ok(created) => info!("Created {} missions from series", created),
+            Err(e) => error!("Failed to materialize mission series: {}", e),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_management::MissionManagementUseCase,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                activity::ActivityPostgres, guild::GuildPostgres,
+                mission_management::MissionManagementPostgres,
+                mission_template::MissionTemplatePostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        image_storage::ImageStorageBackend,
+    },
+};
+
+pub async fn run(
+    db_pool: Arc<PgPoolSquad>,
+    image_storage: Arc<ImageStorageBackend>,
+    period: Duration,
+) {
+    let user_case = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(GuildPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(ActivityPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionTemplatePostgres::new(Arc::clone(&db_pool))),
+        image_storage,
+    );
+
+    let mut ticker = interval(period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        match user_case.purge_deleted(Utc::now().naive_utc()).await {
+            Ok(0) => {}
+            Ok(purged) => info!("Purged {} deleted missions", purged),
+            Err(e) => error!("Failed to purge deleted missions: {}", e),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+pub mod matchmaking;
+pub mod mission_series;
+pub mod mission_trash;
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::Result;
+
+use crate::{
+    config::config_loader::{get_matchmaking_env, get_scheduler_env, get_trash_env},
+    infrastructure::{
+        database::postgresql_connection::PgPoolSquad, image_storage::ImageStorageBackend,
+    },
+};
+
+pub fn spawn(db_pool: Arc<PgPoolSquad>, image_storage: Arc<ImageStorageBackend>) -> Result<()> {
+    let scheduler_env = get_scheduler_env()?;
+    let interval = Duration::from_secs(scheduler_env.interval_secs.max(1));
+
+    tokio::spawn(mission_series::run(Arc::clone(&db_pool), interval));
+
+    let trash_env = get_trash_env()?;
+    let interval = Duration::from_secs(trash_env.purge_interval_secs.max(1));
+
+    tokio::spawn(mission_trash::run(
+        Arc::clone(&db_pool),
+        Arc::clone(&image_storage),
+        interval,
+    ));
+
+    let matchmaking_env = get_matchmaking_env()?;
+    let interval = Duration::from_secs(matchmaking_env.interval_secs.max(1));
+
+    tokio::spawn(matchmaking::run(
+        Arc::clone(&db_pool),
+        Arc::clone(&image_storage),
+        interval,
+    ));
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::config::config_loader::get_jwt_env;
+use crate::domain::value_objects::brawler_model::avatar_or_default;
 use crate::infrastructure::jwt::generate_token;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,7 +29,7 @@ impl Passport {
         Ok(Self {
             token,
             display_name,
-            avatar_url,
+            avatar_url: avatar_or_default(avatar_url, user_id),
         })
     }
 }
@@ -1,5 +1,7 @@
 pub mod argon2;
-pub mod cloudinary;
 pub mod database;
 pub mod http;
+pub mod image_processing;
+pub mod image_storage;
+pub mod jobs;
 pub mod jwt;
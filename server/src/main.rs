@@ -2,9 +2,14 @@ use std::sync::Arc;
 
 use server::{
     config::config_loader,
-    infrastructure::{database::postgresql_connection, http::http_serv::start},
+    infrastructure::{
+        database::{migration_runner, postgresql_connection},
+        http::http_serv::start,
+        image_storage::ImageStorageBackend,
+        jobs,
+    },
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() {
@@ -32,7 +37,37 @@ async fn main() {
     };
     info!("Connected DB");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
+    match migration_runner::migrate(&postgres_pool, dotenvy_env.database.migrate_on_start) {
+        Ok(pending) if !pending.is_empty() => warn!(
+            "{} pending migrations ({}), set DATABASE_MIGRATE_ON_START=true to apply them",
+            pending.len(),
+            pending.join(", ")
+        ),
+        Ok(_) => {}
+        Err(e) => {
+            error!("Failed to migrate DB: {}", e);
+            std::process::exit(1);
+        }
+    }
+    info!("DB migrations checked");
+
+    let postgres_pool = Arc::new(postgres_pool);
+
+    let image_storage = match ImageStorageBackend::from_env() {
+        Ok(image_storage) => Arc::new(image_storage),
+        Err(e) => {
+            error!("Failed to set up image storage: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(e) = jobs::spawn(Arc::clone(&postgres_pool), Arc::clone(&image_storage)) {
+        error!("Failed to start background jobs: {}", e);
+        std::process::exit(1);
+    }
+    info!("Background jobs started");
+
+    start(Arc::new(dotenvy_env), postgres_pool, image_storage)
         .await
         .expect("Failed to start server");
 }